@@ -3,7 +3,7 @@ use self::{
 	parameter_helper_definitions::{CustomArgument, ParameterHelperDefinitions},
 };
 use crate::{
-	asteracea_ident,
+	asteracea_path, parse_asteracea_attributes,
 	part::{GenerateContext, LetSelf},
 	storage_configuration::StorageTypeConfiguration,
 	storage_context::{ParseContext, ParseWithContext, StorageContext},
@@ -32,6 +32,7 @@ use unquote::unquote;
 mod arguments;
 mod parameter_helper_definitions;
 mod kw {
+	syn::custom_keyword!(emits);
 	syn::custom_keyword!(new);
 	syn::custom_keyword!(Sync);
 	syn::custom_keyword!(with);
@@ -56,7 +57,10 @@ pub struct ComponentDeclaration {
 	constructor_block: Option<(kw::new, kw::with, Braced)>,
 	body: Part<ComponentRenderConfiguration>,
 	assorted_items: Vec<Item>,
-	callback_registrations: Vec<(Ident, Type)>,
+	callback_registrations: Vec<(Ident, Type, Option<Type>)>,
+	async_event_handlers: Option<Span>,
+	property_bindings: Option<Span>,
+	dom_events: Option<Span>,
 }
 
 pub enum RenderType {
@@ -84,6 +88,8 @@ impl Configuration for ComponentRenderConfiguration {
 
 impl Parse for ComponentDeclaration {
 	fn parse(input: ParseStream<'_>) -> Result<Self> {
+		parse_asteracea_attributes(input)?;
+
 		let attributes = input.call(Attribute::parse_outer)?;
 		let visibility = input.parse()?;
 		let async_ = input.parse().expect("infallible");
@@ -98,64 +104,31 @@ impl Parse for ComponentDeclaration {
 			Generics::default()
 		};
 
-		if input.peek(Token![where]) {
-			let where_token = input.parse::<Token![where]>()?;
-			let mut component_wheres: Vec<WherePredicate> = Vec::new();
-			loop {
-				let forked = input.fork();
-				if let Ok(predicate) = forked.parse() {
-					let comma_required = !forked.peek(Token![#]);
-					if comma_required && forked.parse::<Token![,]>().is_err() {
-						return Err(Error::new_spanned(
-							predicate,
-							"A component's where clause must end with a comma unless it is followed by an attribute.",
-						));
-					}
-					component_wheres.push(predicate);
-
-					// Not great, but it's still the cleanest solution I think.
-					// No generics are valid where predicates, since they'd have to be followed by :.
-					discouraged::Speculative::advance_to(input, &forked);
-					if !comma_required {
-						break;
-					}
-				} else {
-					break;
-				}
-			}
-			if component_wheres.is_empty() {
-				warn(
-					input.cursor().span(),
-					"No where predicate found.
-					Did you forget to end it with a comma?",
-				)?;
-			} else {
-				component_generics.where_clause = component_generics
-					.where_clause
-					.add(&Some(WhereClause {
-						where_token,
-						predicates: component_wheres.into_iter().collect(),
-					}))
-					.as_deref()
-					.cloned()
-			}
+		if let Some(where_clause) = parse_where_clause(input, "A component's")? {
+			component_generics.where_clause = component_generics
+				.where_clause
+				.add(&Some(where_clause))
+				.as_deref()
+				.cloned()
 		}
 		let component_generics = component_generics;
 
 		let constructor_attributes = input.call(Attribute::parse_outer)?;
 
-		//TODO: Where clause, somehow.
-		let constructor_generics = if input.peek(Token![<]) {
-			input.parse()?
+		let mut constructor_generics = if input.peek(Token![<]) {
+			input.parse::<Generics>()?
 		} else {
 			Generics::default()
 		};
+		constructor_generics.where_clause = parse_where_clause(input, "A constructor's")?;
 
 		if !input.peek(Paren) {
 			let message = "Expected parentheses (constructor arguments).".to_string();
 			return Err(Error::new(
 				input.cursor().span(),
-				if matches!(component_generics.where_clause, Some(where_clause) if !where_clause.predicates.is_empty())
+				if constructor_generics.where_clause.is_some() {
+					message + "\nDid you forget to end the constructor where clause with a comma?"
+				} else if matches!(component_generics.where_clause, Some(where_clause) if !where_clause.predicates.is_empty())
 				{
 					message + "\nDid you forget to end the component where clause with a comma?"
 				} else {
@@ -169,21 +142,49 @@ impl Parse for ComponentDeclaration {
 
 		let render_attributes = input.call(Attribute::parse_outer)?;
 
-		//TODO: Where clause, somehow.
-		let render_generics = if input.peek(Token![<]) {
-			input.parse()?
+		let mut render_generics = if input.peek(Token![<]) {
+			input.parse::<Generics>()?
 		} else {
 			Generics::default()
 		};
+		render_generics.where_clause = parse_where_clause(input, "A `.render` method's")?;
 
+		if !input.peek(Paren) && render_generics.where_clause.is_some() {
+			return Err(Error::new(
+				input.cursor().span(),
+				"Expected parentheses (render arguments).\nDid you forget to end the `.render` method's where clause with a comma?",
+			));
+		}
 		let render_args;
 		let render_paren = parenthesized!(render_args in input); //TODO: Specify error message.
-		let render_args = Punctuated::parse_terminated(&render_args)?;
+		let mut render_args: Punctuated<Argument, Token![,]> =
+			Punctuated::parse_terminated(&render_args)?;
 
 		let render_type = input.parse()?;
 
+		let emits = parse_emits(input)?;
+
 		let mut cx = ParseContext::new_root(&visibility, &component_name, &component_generics);
 
+		for (name, ty) in emits {
+			let asteracea = asteracea_path(name.span());
+			let marker = emitted_event_marker(&component_name, &name);
+			let (impl_generics, type_generics, where_clause) = component_generics.split_for_impl();
+			cx.assorted_items.push(parse_quote_spanned! {name.span()=>
+				#[doc(hidden)]
+				#[allow(non_camel_case_types)]
+				#visibility struct #marker;
+			});
+			cx.assorted_items.push(parse_quote_spanned! {name.span()=>
+				impl #impl_generics #asteracea::__::Emits<#marker> for #component_name #type_generics #where_clause {
+					type Payload = #ty;
+				}
+			});
+			render_args.push(parse2(quote_spanned! {name.span()=>
+				#name?: #asteracea::include::Emitter<#ty>
+			})?);
+		}
+
 		let constructor_block = if input.peek(kw::new) {
 			unquote! {input,
 				#let new
@@ -208,7 +209,10 @@ impl Parse for ComponentDeclaration {
 				capture: arguments::Capture::Yes(visibility),
 				injection_dyn,
 				argument: Argument {
-					fn_arg, question, ..
+					fn_arg,
+					question,
+					default,
+					..
 				},
 			} = constructor_argument
 			{
@@ -226,19 +230,22 @@ impl Parse for ComponentDeclaration {
 					let mut ty = match injection_dyn {
 						None => Type::clone(ty),
 						Some(dyn_) => {
-							let asteracea = asteracea_ident(dyn_.span);
+							let asteracea = asteracea_path(dyn_.span);
 							parse_quote_spanned! {dyn_.span.resolved_at(Span::mixed_site())=>
 								<
-									<#ty as ::#asteracea::__::rhizome::sync::Extract>::Extracted
-									as ::#asteracea::__::rhizome::sync::Extracted<::core::any::TypeId>
+									<#ty as #asteracea::__::rhizome::sync::Extract>::Extracted
+									as #asteracea::__::rhizome::sync::Extracted<::core::any::TypeId>
 								>::Extracted
 							}
 						}
 					};
 
 					if let Some(question) = question {
-						ty = parse_quote_spanned! {question.span.resolved_at(Span::mixed_site())=>
-							::core::option::Option::<#ty>
+						// Injected dependencies with a default are always present.
+						if injection_dyn.is_none() || default.is_none() {
+							ty = parse_quote_spanned! {question.span.resolved_at(Span::mixed_site())=>
+								::core::option::Option::<#ty>
+							}
 						}
 					}
 
@@ -276,9 +283,26 @@ impl Parse for ComponentDeclaration {
 			assorted_items,
 			storage_context,
 			callback_registrations,
+			async_event_handlers,
+			property_bindings,
+			dom_events,
 			..
 		} = cx;
 
+		if let (RenderType::Sync(_, sync), Some(async_)) =
+			(&render_type, async_event_handlers.get())
+		{
+			let mut error = Error::new(
+				async_,
+				"`async` event handlers are only available in thread-bound components, as their futures borrow the component.",
+			);
+			error.combine(Error::new(
+				sync.span,
+				"Consider rendering `-> Sync?` or `-> !Sync` instead.",
+			));
+			return Err(error);
+		}
+
 		Ok(Self {
 			assorted_items,
 			attributes,
@@ -303,10 +327,108 @@ impl Parse for ComponentDeclaration {
 					"Internal Asteracea error: `callback_registrations` still referenced elsewhere",
 				)
 				.into_inner(),
+			async_event_handlers: async_event_handlers.get(),
+			property_bindings: property_bindings.get(),
+			dom_events: dom_events.get(),
 		})
 	}
 }
 
+/// Parses an optional `emits name: Type, …` clause (after the render type), in which each entry ends with a comma.
+fn parse_emits(input: ParseStream) -> Result<Vec<(Ident, Type)>> {
+	if !input.peek(kw::emits) {
+		return Ok(vec![]);
+	}
+	let emits = input.parse::<kw::emits>()?;
+
+	let mut entries = vec![];
+	while input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+		let name: Ident = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty: Type = input.parse()?;
+		input.parse::<Token![,]>().map_err(|error| {
+			Error::new(
+				error.span(),
+				"Expected `,`. (Each emitted event declaration must end with a comma.)",
+			)
+		})?;
+		entries.push((name, ty));
+	}
+	if entries.is_empty() {
+		return Err(Error::new(
+			emits.span,
+			"Expected at least one emitted event declaration (`name: Type,`).",
+		));
+	}
+	Ok(entries)
+}
+
+/// The name of the marker type that identifies the emitted event `event` of component `component_name`.
+///
+/// Parent components use this to look up the payload type for `<*Child on event = …>` via `Emits<Marker>::Payload`,
+/// which (unlike a type alias) can depend on the child's generics.
+pub fn emitted_event_marker(component_name: &Ident, event: &Ident) -> Ident {
+	Ident::new(
+		&format!(
+			"{}__Asteracea__emits_{}",
+			component_name.to_string().trim_start_matches("r#"),
+			event.to_string().trim_start_matches("r#")
+		),
+		component_name.span(),
+	)
+}
+
+/// Parses an optional `where` clause whose predicates each end with a comma,
+/// except for the last one if it's followed by an attribute.
+///
+/// `owner` is used in error messages, e.g. "A component's".
+fn parse_where_clause(input: ParseStream, owner: &str) -> Result<Option<WhereClause>> {
+	if !input.peek(Token![where]) {
+		return Ok(None);
+	}
+
+	let where_token = input.parse::<Token![where]>()?;
+	let mut wheres: Vec<WherePredicate> = Vec::new();
+	loop {
+		let forked = input.fork();
+		if let Ok(predicate) = forked.parse() {
+			let comma_required = !forked.peek(Token![#]);
+			if comma_required && forked.parse::<Token![,]>().is_err() {
+				return Err(Error::new_spanned(
+					predicate,
+					format!(
+						"{} where clause must end with a comma unless it is followed by an attribute.",
+						owner
+					),
+				));
+			}
+			wheres.push(predicate);
+
+			// Not great, but it's still the cleanest solution I think.
+			// No generics are valid where predicates, since they'd have to be followed by :.
+			discouraged::Speculative::advance_to(input, &forked);
+			if !comma_required {
+				break;
+			}
+		} else {
+			break;
+		}
+	}
+	if wheres.is_empty() {
+		warn(
+			input.cursor().span(),
+			"No where predicate found.
+			Did you forget to end it with a comma?",
+		)?;
+		Ok(None)
+	} else {
+		Ok(Some(WhereClause {
+			where_token,
+			predicates: wheres.into_iter().collect(),
+		}))
+	}
+}
+
 impl Parse for RenderType {
 	fn parse(input: ParseStream) -> Result<Self> {
 		match input.parse().unwrap() {
@@ -349,25 +471,55 @@ impl ComponentDeclaration {
 			body,
 			assorted_items: mut random_items,
 			callback_registrations,
+			async_event_handlers,
+			property_bindings,
+			dom_events,
 		} = self;
 
-		let asteracea = asteracea_ident(Span::call_site());
+		let asteracea = asteracea_path(Span::call_site());
+
+		let render_self: Token![self] = parse2(quote_spanned!(render_paren.span=> self)).unwrap();
 
 		let mut unsafe_drop_early = TokenStream::new();
-		for (name, parameter_type) in callback_registrations {
+		let mut rewind_payload_registrations = TokenStream::new();
+		for (name, parameter_type, payload_type) in callback_registrations {
+			let (field_type, initial_value) = if let Some(payload_type) = payload_type {
+				// Each render claims payload slots from the front again. Unclaimed ones are released when the guard is dropped at its end.
+				rewind_payload_registrations.extend(
+					quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
+						let _rewound = #render_self.#name.rewind();
+					},
+				);
+				(
+					parse_quote_spanned! {payload_type.span().resolved_at(Span::mixed_site())=>
+						#asteracea::__::DroppablePayloadCallbackRegistrations::<
+							#component_name,
+							#payload_type,
+						>
+					},
+					quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
+						#asteracea::__::DroppablePayloadCallbackRegistrations::default()
+					},
+				)
+			} else {
+				(
+					parse_quote_spanned! {parameter_type.span().resolved_at(Span::mixed_site())=>
+						#asteracea::__::DroppableLazyCallbackRegistration::<
+							#component_name,
+							fn(#parameter_type),
+						>
+					},
+					quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
+						#asteracea::__::DroppableLazyCallbackRegistration::default()
+					},
+				)
+			};
 			storage_context.push(FieldDefinition {
 				attributes: vec![],
 				visibility: Visibility::Inherited,
 				name: name.clone(),
-				field_type: parse_quote_spanned! {parameter_type.span().resolved_at(Span::mixed_site())=>
-					::#asteracea::__::DroppableLazyCallbackRegistration::<
-						#component_name,
-						fn(#parameter_type),
-					>
-				},
-				initial_value: quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
-					::#asteracea::__::DroppableLazyCallbackRegistration::default()
-				},
+				field_type,
+				initial_value,
 				structurally_pinned: true, // This isn't quite clean, but it implies asserting `!Unpin` on the component type.
 			});
 
@@ -379,11 +531,84 @@ impl ComponentDeclaration {
 			assert!(name.to_string().contains("__Asteracea__"));
 			unsafe_drop_early.extend(
 				quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
-					::#asteracea::__::DroppableLazyCallbackRegistration::drop(&mut self.#name);
+					::std::mem::ManuallyDrop::drop(&mut self.#name);
 				},
 			)
 		}
 
+		if let Some(span) = async_event_handlers {
+			let name = Ident::new(
+				"__Asteracea__event_tasks",
+				span.resolved_at(Span::mixed_site()),
+			);
+			storage_context.push(FieldDefinition {
+				attributes: vec![],
+				visibility: Visibility::Inherited,
+				name: name.clone(),
+				field_type: parse_quote_spanned! {span.resolved_at(Span::mixed_site())=>
+					::std::mem::ManuallyDrop<#asteracea::include::EventTasks>
+				},
+				initial_value: quote_spanned! {span.resolved_at(Span::mixed_site())=>
+					::std::mem::ManuallyDrop::new(#asteracea::include::EventTasks::new(parent_node)?)
+				},
+				structurally_pinned: false,
+			});
+
+			// IMPORTANT: Event tasks must be dropped after the callback registrations (so that no new ones are started),
+			// but before any other fields, since they may borrow the component.
+			unsafe_drop_early.extend(quote_spanned! {span.resolved_at(Span::mixed_site())=>
+				::std::mem::ManuallyDrop::drop(&mut self.#name);
+			});
+		}
+
+		// Services used by generated code outside of user-visible injections.
+		for (span, name, service, required) in [
+			(
+				property_bindings,
+				"__Asteracea__dom_properties",
+				"DomProperties",
+				false,
+			),
+			(dom_events, "__Asteracea__dom_events", "DomEvents", true),
+		] {
+			if let Some(span) = span {
+				let span = span.resolved_at(Span::mixed_site());
+				let service = Ident::new(service, span);
+				let extracted = quote_spanned! {span=>
+					<dyn #asteracea::services::#service as #asteracea::__::rhizome::sync::Extract>::extract(parent_node)
+						.map_err(#asteracea::error::IncompatibleRuntimeDependency::<dyn #asteracea::services::#service>::new_and_log)
+						.map_err(#asteracea::error::Escalate::escalate)?
+				};
+				let (field_type, initial_value) = if required {
+					(
+						parse_quote_spanned! {span=>
+							#asteracea::services::ServiceHandle<dyn #asteracea::services::#service>
+						},
+						quote_spanned! {span=>
+							#extracted
+								.ok_or_else(#asteracea::error::RuntimeDependencyMissing::<dyn #asteracea::services::#service>::new_and_log)
+								.map_err(#asteracea::error::Escalate::escalate)?
+						},
+					)
+				} else {
+					(
+						parse_quote_spanned! {span=>
+							::core::option::Option<#asteracea::services::ServiceHandle<dyn #asteracea::services::#service>>
+						},
+						extracted,
+					)
+				};
+				storage_context.push(FieldDefinition {
+					attributes: vec![],
+					visibility: Visibility::Inherited,
+					name: Ident::new(name, span),
+					field_type,
+					initial_value,
+					structurally_pinned: false,
+				});
+			}
+		}
+
 		let struct_definition = StorageTypeConfiguration::new_component_root(
 			component_name.clone(),
 			component_generics.clone(),
@@ -420,11 +645,11 @@ impl ComponentDeclaration {
 				prefer_thread_safe: Some(quote_spanned!(r_arrow.span()=> .prefer_thread_safe())),
 			},
 			RenderType::Sync(_, sync) => GenerateContext {
-				thread_safety: quote_spanned!(sync.span.resolved_at(Span::mixed_site())=> ::#asteracea::lignin::ThreadSafe),
+				thread_safety: quote_spanned!(sync.span.resolved_at(Span::mixed_site())=> #asteracea::lignin::ThreadSafe),
 				prefer_thread_safe: None,
 			},
 			RenderType::UnSync(_, _, sync) => GenerateContext {
-				thread_safety: quote_spanned!(sync.span.resolved_at(Span::mixed_site())=> ::#asteracea::lignin::ThreadBound),
+				thread_safety: quote_spanned!(sync.span.resolved_at(Span::mixed_site())=> #asteracea::lignin::ThreadBound),
 				prefer_thread_safe: None,
 			},
 		};
@@ -452,9 +677,9 @@ impl ComponentDeclaration {
 				.resolved_at(Span::mixed_site());
 			let ty = &*injected_arg.argument.fn_arg.ty;
 			let value = quote_spanned! {span=>
-				<#ty as ::#asteracea::__::rhizome::sync::Extract>::extract(parent_node)
-					.map_err(::#asteracea::error::IncompatibleRuntimeDependency::<#ty>::new_and_log)
-					.map_err(::#asteracea::error::Escalate::escalate)?
+				<#ty as #asteracea::__::rhizome::sync::Extract>::extract(parent_node)
+					.map_err(#asteracea::error::IncompatibleRuntimeDependency::<#ty>::new_and_log)
+					.map_err(#asteracea::error::Escalate::escalate)?
 			};
 			let value = match (
 				injected_arg.argument.question.as_ref(),
@@ -462,16 +687,15 @@ impl ComponentDeclaration {
 			) {
 				(None, None) => quote_spanned! {span=>
 					#value
-						.ok_or_else(|| ::#asteracea::error::RuntimeDependencyMissing::<#ty>::new_and_log())
-						.map_err(::#asteracea::error::Escalate::escalate)?
+						.ok_or_else(|| #asteracea::error::RuntimeDependencyMissing::<#ty>::new_and_log())
+						.map_err(#asteracea::error::Escalate::escalate)?
 				},
-				(None, Some((eq, default))) => {
+				(_, Some((eq, default))) => {
 					quote_spanned! {eq.span.resolved_at(Span::mixed_site())=>
 						#value.unwrap_or_else(|| #default)
 					}
 				}
 				(Some(_), None) => value,
-				(Some(_), Some(_)) => todo!("explicitly optional dependency with default"),
 			};
 			dependency_extractions.push(value);
 		}
@@ -550,8 +774,8 @@ impl ComponentDeclaration {
 				Member::Unnamed(_) => unreachable!(),
 				Member::Named(arg_ident) => {
 					quote_spanned!(arg_ident.span().resolved_at(Span::mixed_site())=> #arg_ident = {
-						use ::#asteracea::__::CoerceTracingValue;
-						(&&&&&::#asteracea::__::InertWrapper(&args.#arg_ident)).coerce()
+						use #asteracea::__::CoerceTracingValue;
+						(&&&&&#asteracea::__::InertWrapper(&args.#arg_ident)).coerce()
 					})
 				}
 			})
@@ -573,8 +797,8 @@ impl ComponentDeclaration {
 				Member::Unnamed(_) => unreachable!(),
 				Member::Named(arg_ident) => {
 					quote_spanned!(arg_ident.span().resolved_at(Span::mixed_site())=> #arg_ident = {
-						use ::#asteracea::__::CoerceTracingValue;
-						(&&&&&::#asteracea::__::InertWrapper(&args.#arg_ident)).coerce()
+						use #asteracea::__::CoerceTracingValue;
+						(&&&&&#asteracea::__::InertWrapper(&args.#arg_ident)).coerce()
 					})
 				}
 			})
@@ -595,8 +819,6 @@ impl ComponentDeclaration {
 			component_name.span(),
 		);
 
-		let render_self: Token![self] = parse2(quote_spanned!(render_paren.span=> self)).unwrap();
-
 		let render_type: ReturnType = match render_type {
 			RenderType::AutoSafe => {
 				let auto_safe = Ident::new(
@@ -606,14 +828,14 @@ impl ComponentDeclaration {
 				);
 				random_items.push(
 					parse2(quote! {
-						::#asteracea::lignin::auto_safety::AutoSafe_alias!(pub(crate) #auto_safe);
+						#asteracea::lignin::auto_safety::AutoSafe_alias!(pub(crate) #auto_safe);
 					})
 					.expect("RenderType::AutoSafe __Asteracea__AutoSafe"),
 				);
 				parse2(quote! {
 					-> ::std::result::Result<
-						impl #auto_safe<::#asteracea::lignin::Node<'bump, ::#asteracea::lignin::ThreadBound>>,
-						::#asteracea::error::Escalation,
+						impl #auto_safe<#asteracea::lignin::Node<'bump, #asteracea::lignin::ThreadBound>>,
+						#asteracea::error::Escalation,
 					>
 				})
 				.expect("render_type AutoSafe")
@@ -621,15 +843,15 @@ impl ComponentDeclaration {
 			RenderType::Explicit(r_arrow, type_) => ReturnType::Type(
 				r_arrow,
 				parse2(quote_spanned! {r_arrow.span()=>
-					::std::result::Result<#type_, ::#asteracea::error::Escalation>
+					::std::result::Result<#type_, #asteracea::error::Escalation>
 				})
 				.expect("RenderType::Explicit"),
 			),
 			RenderType::ExplicitAutoSync(_, _, question) => {
 				parse2(quote_spanned! {question.span=>
 					-> ::std::result::Result<
-						impl ::#asteracea::lignin::auto_safety::AutoSafe::<::#asteracea::lignin::Node<'bump, ::#asteracea::lignin::ThreadBound>>,
-						::#asteracea::error::Escalation,
+						impl #asteracea::lignin::auto_safety::AutoSafe::<#asteracea::lignin::Node<'bump, #asteracea::lignin::ThreadBound>>,
+						#asteracea::error::Escalation,
 					>
 				})
 				.expect("render_type AutoSafe")
@@ -637,7 +859,7 @@ impl ComponentDeclaration {
 			RenderType::Sync(r_arrow, _) | RenderType::UnSync(r_arrow, _, _) => {
 				let thread_safety = &cx.thread_safety;
 				parse2(quote_spanned! {r_arrow.span()=>
-					-> ::std::result::Result<::#asteracea::lignin::Node<'bump, #thread_safety>, ::#asteracea::error::Escalation>
+					-> ::std::result::Result<#asteracea::lignin::Node<'bump, #thread_safety>, #asteracea::error::Escalation>
 				})
 				.expect("render_type explicit thread safety")
 			}
@@ -650,6 +872,15 @@ impl ComponentDeclaration {
 
 		let (component_impl_generics, component_type_generics, component_where_clause) =
 			component_generics.split_for_impl();
+		let new_args_where_clause = &new_args_generics.where_clause;
+		let new_where_predicates = new_generics
+			.where_clause
+			.iter()
+			.flat_map(|where_clause| where_clause.predicates.iter());
+		let new_args_builder_where_clause = &new_args_builder_generics.where_clause;
+		let render_args_where_clause = &render_args_generics.where_clause;
+		let render_where_clause = &render_generics.where_clause;
+		let render_args_builder_where_clause = &render_args_builder_generics.where_clause;
 
 		// This (hopefully) enables unused function warnings.
 		let new = Ident::new("new", component_name.span());
@@ -667,7 +898,7 @@ impl ComponentDeclaration {
 			Some(quote_spanned! {Span::mixed_site()=>
 				// Tracing's `#[instrument]` macro is slightly unwieldy in terms of compilation.
 				// The following should be equivalent to skipping all fields and setting them one by one:
-				let _tracing_span = ::#asteracea::__::tracing::debug_span!(#new_span_name, #(#constructor_args_tracing_fields,)*).entered();
+				let _tracing_span = #asteracea::__::tracing::debug_span!(#new_span_name, #(#constructor_args_tracing_fields,)*).entered();
 			})
 		} else {
 			None
@@ -678,13 +909,13 @@ impl ComponentDeclaration {
 			#[derive(#asteracea::__::typed_builder::TypedBuilder)]
 			#[builder(doc)]
 			#[allow(non_snake_case)]
-			#visibility struct #new_args_name #new_args_generics #new_args_body
+			#visibility struct #new_args_name #new_args_generics #new_args_where_clause #new_args_body
 
 			//TODO: Doc comment referring to associated type.
 			#[derive(#asteracea::__::typed_builder::TypedBuilder)]
 			#[builder(doc)]
 			#[allow(non_snake_case)]
-			#visibility struct #render_args_name #render_args_generics #render_args_body
+			#visibility struct #render_args_name #render_args_generics #render_args_where_clause #render_args_body
 
 			#(#struct_definition)*
 
@@ -692,13 +923,13 @@ impl ComponentDeclaration {
 				/// <!-- (suppress `missing_docs`) -->
 				#(#constructor_attributes)*
 				pub #async_ fn #new #new_generics(
-					parent_node: ::core::pin::Pin<&::#asteracea::__::rhizome::sync::Node<
+					parent_node: ::core::pin::Pin<&#asteracea::__::rhizome::sync::Node<
 						::core::any::TypeId,
 						::core::any::TypeId,
-						::#asteracea::__::rhizome::sync::DynValue,
+						#asteracea::__::rhizome::sync::DynValue,
 					>>,
 					args: #new_args_name #new_args_generic_args,
-				) -> ::std::result::Result<Self, ::#asteracea::error::Escalation> where Self: 'a + 'static { // TODO: Self: 'static is necessary because of `derive_for::<Self>`, but that's not really a good approach... Using derived IDs would be better.
+				) -> ::std::result::Result<Self, #asteracea::error::Escalation> where Self: 'a + 'static, #(#new_where_predicates,)* { // TODO: Self: 'static is necessary because of `derive_for::<Self>`, but that's not really a good approach... Using derived IDs would be better.
 					#constructor_tracing_span
 
 					// These are assigned at once to make sure name collisions error.
@@ -718,7 +949,7 @@ impl ComponentDeclaration {
 
 				/// <!-- (suppress `missing_docs`) -->
 				pub fn new_args_builder #new_args_builder_generics()
-				-> #new_args_builder_name #new_args_builder_generic_args {
+				-> #new_args_builder_name #new_args_builder_generic_args #new_args_builder_where_clause {
 					#new_args_name::builder()
 				}
 
@@ -728,23 +959,25 @@ impl ComponentDeclaration {
 					#render_self: ::std::pin::Pin<&'a Self>,
 					#bump: &'bump #asteracea::bumpalo::Bump,
 					args: #render_args_name #render_args_generic_args,
-				) #render_type {
+				) #render_type #render_where_clause {
 					// Tracing's `#[instrument]` macro is slightly unwieldy in terms of compilation.
 					// The following should be equivalent to skipping all fields and setting them one by one:
-					let _tracing_span = ::#asteracea::__::tracing::debug_span!(#render_span_name, #(#render_args_tracing_fields,)*).entered();
+					let _tracing_span = #asteracea::__::tracing::debug_span!(#render_span_name, #(#render_args_tracing_fields,)*).entered();
 
 					let #render_args_name {
 						#(#render_args_field_patterns,)*
 						__Asteracea__phantom: _,
 					} = args;
 
+					#rewind_payload_registrations
+
 					let this = #render_self;
 					::std::result::Result::Ok(#body)
 				}
 
 				/// <!-- (suppress `missing_docs`) -->
 				pub fn render_args_builder #render_args_builder_generics()
-				-> #render_args_builder_name #render_args_builder_generic_args {
+				-> #render_args_builder_name #render_args_builder_generic_args #render_args_builder_where_clause {
 					#render_args_name::builder()
 				}
 
@@ -752,7 +985,7 @@ impl ComponentDeclaration {
 				#[doc(hidden)] // This is used for inference in generated code.
 				#[allow(non_snake_case)]
 				pub fn __Asteracea__ref_render_args_builder #render_args_builder_generics(&self)
-				-> #render_args_builder_name #render_args_builder_generic_args {
+				-> #render_args_builder_name #render_args_builder_generic_args #render_args_builder_where_clause {
 					let _ = self;
 					#render_args_name::builder()
 				}
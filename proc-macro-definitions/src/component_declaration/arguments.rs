@@ -9,7 +9,7 @@ use syn::{
 use tap::Pipe;
 use unquote::unquote;
 
-use crate::{asteracea_ident, util::SinglePat};
+use crate::{asteracea_path, util::SinglePat};
 
 pub struct ConstructorArgument {
 	pub capture: Capture,
@@ -86,24 +86,49 @@ impl Parse for ConstructorArgument {
 impl Parse for Argument {
 	fn parse(input: ParseStream) -> Result<Self> {
 		let attrs = Attributes::parse_outer(input)?;
-		if let Some(dot2) = input.parse::<Option<Token![..]>>().expect("infallible") {
+		if let Some(dot3) = input.parse::<Option<Token![...]>>().expect("infallible") {
+			// This is a multi-use content argument.
+			let bump = quote_spanned! (dot3.span()=>
+				'bump
+			);
+			let asteracea = asteracea_path(dot3.span());
+			Self {
+				fn_arg: match parse_quote_spanned! {dot3.span().resolved_at(Span::mixed_site())=>
+					mut __Asteracea__anonymous_content: (
+						#asteracea::__::AnonymousContentParentParameters,
+						::std::boxed::Box::<
+							#asteracea::include::render_callback::RenderMut::<
+								'_,
+								#bump,
+								#asteracea::lignin::ThreadSafe,
+							>
+						>,
+					)
+				} {
+					FnArg::Receiver(_) => unreachable!(),
+					FnArg::Typed(pat_type) => pat_type,
+				},
+				question: None,
+				default: None,
+			}
+		} else if let Some(dot2) = input.parse::<Option<Token![..]>>().expect("infallible") {
 			// This is a content argument.
 			// For now, only a very minimal feature is supported.
 			let bump = quote_spanned! (dot2.span()=>
 				'bump
 			);
-			let asteracea = asteracea_ident(dot2.span());
+			let asteracea = asteracea_path(dot2.span());
 			Self {
 				fn_arg: match parse_quote_spanned! {dot2.span().resolved_at(Span::mixed_site())=>
 					__Asteracea__anonymous_content: (
-						::#asteracea::__::AnonymousContentParentParameters,
+						#asteracea::__::AnonymousContentParentParameters,
 						::std::boxed::Box::<
-							dyn '_ + ::core::ops::FnOnce(&#bump ::#asteracea::bumpalo::Bump) -> ::std::result::Result::<
-								::#asteracea::lignin::Node::<
+							dyn '_ + ::core::ops::FnOnce(&#bump #asteracea::bumpalo::Bump) -> ::std::result::Result::<
+								#asteracea::lignin::Node::<
 									#bump,
-									::#asteracea::lignin::ThreadSafe,
+									#asteracea::lignin::ThreadSafe,
 								>,
-								::#asteracea::error::Escalation,
+								#asteracea::error::Escalation,
 							>
 						>,
 					)
@@ -7,13 +7,13 @@ use lazy_static::lazy_static;
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use proc_macro_crate::{crate_name, FoundCrate};
-use quote::{quote, quote_spanned};
-use std::iter;
+use quote::{quote, quote_spanned, ToTokens};
+use std::{cell::RefCell, iter};
 use syn::{
 	parse::{Parse, ParseStream},
 	parse_macro_input,
 	spanned::Spanned,
-	Error, Ident, Result,
+	Attribute, Error, Ident, Path, Result,
 };
 use tap::Conv;
 
@@ -28,7 +28,7 @@ mod util;
 use self::{
 	component_declaration::ComponentDeclaration,
 	map_message::MapMessage,
-	part::{GenerateContext, Part},
+	part::{invalid_element_name_char_tokens, GenerateContext, Part},
 };
 
 fn hook_panics() {
@@ -56,6 +56,7 @@ fn hook_panics() {
 #[proc_macro]
 pub fn component(input: TokenStream1) -> TokenStream1 {
 	hook_panics();
+	let _path_guard = AsteraceaPathGuard::new();
 
 	let component_declaration = parse_macro_input!(input as ComponentDeclaration);
 	let tokens: TokenStream2 = component_declaration
@@ -65,13 +66,14 @@ pub fn component(input: TokenStream1) -> TokenStream1 {
 }
 
 struct BumpFormat {
-	asteracea: Ident,
+	asteracea: TokenStream2,
 	bump_span: Span,
 	input: TokenStream2,
 }
 
 #[proc_macro]
 pub fn bump_format(input: TokenStream1) -> TokenStream1 {
+	let _path_guard = AsteraceaPathGuard::new();
 	let bump_format = parse_macro_input!(input as BumpFormat);
 	let mut tokens = TokenStream2::new();
 	bump_format.to_tokens_with_context(
@@ -80,6 +82,7 @@ pub fn bump_format(input: TokenStream1) -> TokenStream1 {
 			thread_safety: quote!(_),
 			prefer_thread_safe: None,
 		},
+		quote!(None),
 	);
 	tokens.into()
 }
@@ -89,7 +92,7 @@ impl Parse for BumpFormat {
 		//TODO: This is pretty hacky.
 		// Change it to a better location once that feature is stable in proc_macro2.
 		let bump_span = input.cursor().span();
-		let asteracea = asteracea_ident(bump_span);
+		let asteracea = asteracea_path(bump_span);
 		Ok(BumpFormat {
 			asteracea,
 			bump_span,
@@ -99,7 +102,12 @@ impl Parse for BumpFormat {
 }
 
 impl BumpFormat {
-	fn to_tokens_with_context(&self, output: &mut TokenStream2, cx: &GenerateContext) {
+	fn to_tokens_with_context(
+		&self,
+		output: &mut TokenStream2,
+		cx: &GenerateContext,
+		dom_binding: TokenStream2,
+	) {
 		let BumpFormat {
 			asteracea,
 			bump_span,
@@ -111,7 +119,7 @@ impl BumpFormat {
 			#asteracea::lignin::Node::Text::<#thread_safety> {
 				text: #asteracea::bumpalo::format!(in #bump, #input)
 					.into_bump_str(),
-				dom_binding: None, //TODO?: Add DOM binding support.
+				dom_binding: #dom_binding,
 			}
 		});
 	}
@@ -125,22 +133,27 @@ impl Configuration for FragmentConfiguration {
 
 #[proc_macro]
 pub fn fragment(input: TokenStream1) -> TokenStream1 {
-	let asteracea = asteracea_ident(Span::mixed_site());
-	let body = parse_macro_input!(input as Part<FragmentConfiguration>)
+	let _path_guard = AsteraceaPathGuard::new();
+	let body = parse_macro_input!(input with parse_fragment)
 		.part_tokens(&GenerateContext {
 			thread_safety: quote!(_),
 			prefer_thread_safe: None,
 		})
 		.unwrap_or_else(|error| error.to_compile_error());
+	let asteracea = asteracea_path(Span::mixed_site());
 	(quote_spanned! {Span::mixed_site()=>
-		((|| -> ::std::result::Result<_, ::#asteracea::error::Escalation> {
+		((|| -> ::std::result::Result<_, #asteracea::error::Escalation> {
 			Ok(#body)
 		})())
 	})
 	.into()
 }
 
-// TODO: Accept reexported asteracea module made available via `use`.
+fn parse_fragment(input: ParseStream) -> Result<Part<FragmentConfiguration>> {
+	parse_asteracea_attributes(input)?;
+	input.parse()
+}
+
 lazy_static! {
 	static ref ASTERACEA_NAME: String = crate_name("asteracea")
 		.map(|found| match found {
@@ -149,8 +162,65 @@ lazy_static! {
 		})
 		.unwrap_or_else(|_| "asteracea".to_owned());
 }
-fn asteracea_ident(span: Span) -> Ident {
-	Ident::new(&*ASTERACEA_NAME, span)
+
+thread_local! {
+	/// Set through `#![asteracea(crate = …)]` for the duration of one macro invocation.
+	static ASTERACEA_PATH_OVERRIDE: RefCell<Option<Path>> = RefCell::new(None);
+}
+
+/// The path to the `asteracea` crate, e.g. `::asteracea` or, if overridden, `::framework::asteracea`.
+fn asteracea_path(span: Span) -> TokenStream2 {
+	ASTERACEA_PATH_OVERRIDE.with(|path_override| match &*path_override.borrow() {
+		Some(path) => path.to_token_stream(),
+		None => {
+			let name = Ident::new(&*ASTERACEA_NAME, span);
+			quote_spanned!(span=> ::#name)
+		}
+	})
+}
+
+/// Clears [`ASTERACEA_PATH_OVERRIDE`] when created and dropped,
+/// so that it can't leak into other macro invocations, even after a parse error.
+struct AsteraceaPathGuard;
+
+impl AsteraceaPathGuard {
+	fn new() -> Self {
+		Self::reset();
+		Self
+	}
+
+	fn reset() {
+		ASTERACEA_PATH_OVERRIDE.with(|path_override| path_override.borrow_mut().take());
+	}
+}
+
+impl Drop for AsteraceaPathGuard {
+	fn drop(&mut self) {
+		Self::reset()
+	}
+}
+
+/// Parses leading `#![asteracea(crate = path)]` attributes, which apply to the rest of the current macro invocation.
+fn parse_asteracea_attributes(input: ParseStream) -> Result<()> {
+	for attribute in input.call(Attribute::parse_inner)? {
+		if !attribute.path().is_ident("asteracea") {
+			return Err(Error::new_spanned(
+				attribute,
+				"Expected `#![asteracea(…)]`. Other inner attributes are not supported here.",
+			));
+		}
+		attribute.parse_nested_meta(|meta| {
+			if meta.path.is_ident("crate") {
+				let path: Path = meta.value()?.parse()?;
+				ASTERACEA_PATH_OVERRIDE
+					.with(|path_override| *path_override.borrow_mut() = Some(path));
+				Ok(())
+			} else {
+				Err(meta.error("Expected `crate = path`."))
+			}
+		})?;
+	}
+	Ok(())
 }
 
 /// SEE: <https://github.com/rust-lang/rust/issues/34537#issuecomment-554590043>
@@ -208,8 +278,8 @@ pub fn fake_span(input: TokenStream1) -> TokenStream1 {
 		.conv::<TokenStream2>()
 		.span()
 		.resolved_at(Span::mixed_site());
-	let asteracea = asteracea_ident(span);
-	quote_spanned!(span=> ::#asteracea::__::tracing::Span).into()
+	let asteracea = asteracea_path(span);
+	quote_spanned!(span=> #asteracea::__::tracing::Span).into()
 }
 
 /// Discards all tokens and outputs an empty block instead,
@@ -222,3 +292,12 @@ pub fn empty_block(input: TokenStream1) -> TokenStream1 {
 		.resolved_at(Span::mixed_site());
 	quote_spanned!(span=> {}).into()
 }
+
+/// Outputs a `|c: char| -> bool` closure that checks whether `c` is forbidden in element names,
+/// so that `::asteracea::__::validate_element_name` applies the same rules as this crate does to literal names.
+#[doc(hidden)]
+#[proc_macro]
+pub fn is_invalid_element_name_char(input: TokenStream1) -> TokenStream1 {
+	drop(input);
+	invalid_element_name_char_tokens().into()
+}
@@ -6,6 +6,8 @@ mod bump_format_shorthand;
 mod component;
 mod content;
 mod defer;
+mod dom_binding;
+mod dyn_branch;
 mod event_binding;
 mod for_;
 mod html_comment;
@@ -15,20 +17,22 @@ mod let_self;
 //TODO: Rename module and struct to `element_expression` / `ElementExpression`, factor out text expressions and value expressions.
 //TODO: Rust expressions shouldn't automatically be blocks except for ones after `with`.
 
-pub use self::let_self::LetSelf;
 use self::{
 	asterisk_for::AsteriskFor, async_::Async, bind::Bind, box_expression::BoxExpression,
 	component::Component, content::Content, defer::Defer, for_::For, html_comment::HtmlComment,
 	html_definition::HtmlDefinition,
 };
+pub use self::{html_definition::invalid_element_name_char_tokens, let_self::LetSelf};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	storage_context::{ParseContext, ParseWithContext},
 	util::{Braced, SinglePat},
 	BumpFormat, Configuration,
 };
 use core::result::Result as coreResult;
 use debugless_unwrap::{DebuglessUnwrap as _, DebuglessUnwrapErr as _};
+use dom_binding::DomBindingDefinition;
+use dyn_branch::{DropPolicy, DynBranch};
 use event_binding::EventBindingDefinition;
 use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{quote_spanned, ToTokens};
@@ -48,7 +52,7 @@ pub(crate) enum Part<C: Configuration> {
 	Async(Async<C>),
 	Bind(Bind<C>),
 	Box(BoxExpression<C>),
-	BumpFormat(BumpFormat),
+	BumpFormat(BumpFormat, Option<DomBindingDefinition>),
 	Content(Content),
 	Comment(HtmlComment),
 	Component(Component<C>),
@@ -79,7 +83,7 @@ pub(crate) enum Part<C: Configuration> {
 		)>,
 	),
 	Multi(Bracket, Vec<Part<C>>),
-	Text(LitStr),
+	Text(LitStr, Option<DomBindingDefinition>),
 	With(kw::with, Braced, Option<Box<Part<C>>>),
 }
 
@@ -96,7 +100,7 @@ impl<C: Configuration> Part<C> {
 			| Part::Async(_)
 			| Part::Bind(_)
 			| Part::Box(_)
-			| Part::BumpFormat(_)
+			| Part::BumpFormat(_, _)
 			| Part::Comment(_)
 			| Part::Component(_)
 			| Part::Content(_)
@@ -107,7 +111,7 @@ impl<C: Configuration> Part<C> {
 			| Part::If(_, _, _, _, _, _)
 			| Part::Match(_, _, _, _, _)
 			| Part::Multi(_, _)
-			| Part::Text(_)
+			| Part::Text(_, _)
 			| Part::With(_, _, _) => PartKind::Child,
 			Part::EventBinding(_) => PartKind::EventBinding,
 		}
@@ -120,14 +124,15 @@ mod kw {
 }
 
 pub enum InitMode {
-	Dyn(Token![dyn]),
+	/// Collects one [`DynBranch`] per branch, in order.
+	Dyn(Token![dyn], Vec<DynBranch>),
 	Spread(kw::spread),
 }
 
 impl Parse for InitMode {
 	fn parse(input: ParseStream) -> Result<Self> {
 		Ok(if let Some(dyn_) = input.parse().unwrap() {
-			InitMode::Dyn(dyn_)
+			InitMode::Dyn(dyn_, vec![])
 		} else if let Some(spread) = input.parse().unwrap() {
 			InitMode::Spread(spread)
 		} else {
@@ -161,7 +166,43 @@ impl<C: Configuration> Parse for Part<C> {
 	}
 }
 
+impl InitMode {
+	fn parse_branch<C: Configuration>(
+		&mut self,
+		input: ParseStream<'_>,
+		cx: &mut ParseContext,
+		policy: DropPolicy,
+	) -> Result<Part<C>> {
+		match self {
+			InitMode::Dyn(dyn_, branches) => {
+				let (branch, part) = DynBranch::parse_with_context(input, cx, dyn_.span, policy)?;
+				branches.push(branch);
+				Ok(part)
+			}
+			InitMode::Spread(_) => match policy {
+				DropPolicy::Drop(None) => Part::parse_required_with_context(input, cx),
+				DropPolicy::Drop(Some(drop)) => Err(Error::new(
+					drop.span,
+					"Storage policies are only available on `dyn` branches.",
+				)),
+				DropPolicy::Retain(retain) => Err(Error::new(
+					retain.span,
+					"Storage policies are only available on `dyn` branches.",
+				)),
+			},
+		}
+	}
+}
+
 impl<C: Configuration> Part<C> {
+	/// The event binding represented by this part, if any.
+	pub fn as_event_binding(&self) -> Option<&EventBindingDefinition> {
+		match self {
+			Part::EventBinding(definition) => Some(definition),
+			_ => None,
+		}
+	}
+
 	pub fn parse_required_with_context(
 		input: ParseStream<'_>,
 		cx: &mut ParseContext,
@@ -209,16 +250,19 @@ impl<C: Configuration> ParseWithContext for Part<C> {
 		} else if input.peek(Token![for]) {
 			Some(Part::For(For::parse_with_context(input, cx)?))
 		} else if lookahead.peek(LitStr) {
-			Some(Part::Text(input.parse()?))
+			Some(Part::Text(
+				input.parse()?,
+				DomBindingDefinition::parse_with_context::<C>(input, cx, "Text")?,
+			))
 		} else if lookahead.peek(Token![<]) {
 			match {
 				let input = input.fork();
 				input.parse::<Token![<]>().unwrap();
 				input.parse::<TokenTree>()?
 			} {
-				TokenTree::Punct(punct) if punct.as_char() == '!' => {
-					Some(Part::Comment(HtmlComment::parse_with_context(input, cx)?))
-				}
+				TokenTree::Punct(punct) if punct.as_char() == '!' => Some(Part::Comment(
+					HtmlComment::parse_with_context::<C>(input, cx)?,
+				)),
 				TokenTree::Punct(punct) if punct.as_char() == '*' => {
 					Some(Part::Component(Component::parse_with_context(input, cx)?))
 				}
@@ -242,28 +286,19 @@ impl<C: Configuration> ParseWithContext for Part<C> {
 					"Unexpected token in `if` condition",
 				));
 			}
-			let then = match &mut init_mode {
-				InitMode::Dyn(_) => {
-					todo!("`dyn if`")
-				}
-				InitMode::Spread(_) => Part::parse_required_with_context(input, cx)?,
-			};
+			let then = init_mode.parse_branch(input, cx, DropPolicy::Drop(None))?;
 			let (else_, else_arm) = if let Some(else_) = input.parse().unwrap() {
 				(
 					else_,
-					match &mut init_mode {
-						InitMode::Dyn(_) => {
-							todo!("`dyn if else`")
-						}
-						InitMode::Spread(_) => Part::parse_required_with_context(input, cx)?,
-					}
-					.pipe(Box::new),
+					init_mode
+						.parse_branch(input, cx, DropPolicy::Drop(None))?
+						.pipe(Box::new),
 				)
 			} else {
 				(
 					Token![else](if_.span),
 					call2_for_syn::call2_strict(quote_spanned!(if_.span=> []), |input| {
-						Part::parse_required_with_context(input, cx)
+						init_mode.parse_branch(input, cx, DropPolicy::Drop(None))
 					})
 					.debugless_unwrap()
 					.unwrap()
@@ -308,13 +343,8 @@ impl<C: Configuration> ParseWithContext for Part<C> {
 						.map(|if_| Result::Ok((if_, input.parse()?)))
 						.transpose()?;
 					let fat_arrow = input.parse()?;
-					let part = match &mut init_mode {
-						InitMode::Dyn(_) => {
-							todo!("`dyn match arm`")
-						}
-						InitMode::Spread(_) => Part::parse_required_with_context(input, cx)?,
-					}
-					.pipe(Box::new);
+					let policy = input.parse()?;
+					let part = init_mode.parse_branch(input, cx, policy)?.pipe(Box::new);
 					arms.push((attrs, pats, guard, fat_arrow, part))
 				}
 				arms
@@ -362,6 +392,7 @@ impl<C: Configuration> ParseWithContext for Part<C> {
 		} else if bump_format_shorthand::peek_from(input) {
 			Some(Part::<C>::BumpFormat(
 				bump_format_shorthand::parse_with_context::<C>(input, cx)?,
+				DomBindingDefinition::parse_with_context::<C>(input, cx, "Text")?,
 			))
 		} else if input.peek(kw::with) {
 			unquote! {input,
@@ -392,6 +423,61 @@ pub struct GenerateContext {
 	pub prefer_thread_safe: Option<TokenStream>,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ElementNamespace {
+	Html,
+	MathMl,
+	Svg,
+}
+
+impl ElementNamespace {
+	/// The namespace of an element named `name` within `self`.
+	pub fn of_element(self, name: &str) -> Self {
+		match name {
+			"math" => Self::MathMl,
+			"svg" => Self::Svg,
+			_ => self,
+		}
+	}
+
+	/// The namespace of children of an element named `name` within `self`.
+	pub fn of_children(self, name: &str) -> Self {
+		match (self.of_element(name), name) {
+			(Self::Svg, "foreignObject") => Self::Html,
+			(namespace, _) => namespace,
+		}
+	}
+
+	/// The matching `lignin_schema` module.
+	pub fn schema_module(self, span: Span) -> Ident {
+		match self {
+			Self::Html => Ident::new("html", span),
+			Self::MathMl => Ident::new("mathml", span),
+			Self::Svg => Ident::new("svg", span),
+		}
+	}
+
+	/// The type of bound DOM elements in `lignin::web`.
+	///
+	/// `lignin::web` has no MathML-specific element type, so `lignin::Node::MathMlElement` binds plain `Element`s.
+	pub fn web_type(self) -> &'static str {
+		match self {
+			Self::Html => "HtmlElement",
+			Self::MathMl => "Element",
+			Self::Svg => "SvgElement",
+		}
+	}
+
+	/// The matching `lignin::Node` variant.
+	pub fn node_variant(self, span: Span) -> Ident {
+		match self {
+			Self::Html => Ident::new("HtmlElement", span),
+			Self::MathMl => Ident::new("MathMlElement", span),
+			Self::Svg => Ident::new("SvgElement", span),
+		}
+	}
+}
+
 impl<C: Configuration> Part<C> {
 	pub fn part_tokens(&self, cx: &GenerateContext) -> Result<TokenStream> {
 		let thread_safety = &cx.thread_safety;
@@ -401,9 +487,13 @@ impl<C: Configuration> Part<C> {
 			Part::Async(async_) => async_.part_tokens(cx)?,
 			Part::Bind(bind) => bind.part_tokens(cx)?,
 			Part::Box(box_expression) => box_expression.part_tokens(cx)?,
-			Part::BumpFormat(bump_format) => {
+			Part::BumpFormat(bump_format, dom_binding) => {
 				let mut tokens = TokenStream::new();
-				bump_format.to_tokens_with_context(&mut tokens, cx);
+				bump_format.to_tokens_with_context(
+					&mut tokens,
+					cx,
+					DomBindingDefinition::binding_tokens(dom_binding.as_ref(), Span::mixed_site()),
+				);
 				tokens
 			}
 			Part::Comment(html_comment) => html_comment.part_tokens(),
@@ -411,37 +501,99 @@ impl<C: Configuration> Part<C> {
 			Part::Content(content) => content.part_tokens(),
 			Part::Defer(defer) => defer.part_tokens(cx)?,
 			Part::For(for_) => for_.part_tokens(cx)?,
-			Part::Text(lit_str) => {
-				let asteracea = asteracea_ident(lit_str.span());
+			Part::Text(lit_str, dom_binding) => {
+				let asteracea = asteracea_path(lit_str.span());
+				let dom_binding =
+					DomBindingDefinition::binding_tokens(dom_binding.as_ref(), lit_str.span());
 				quote_spanned! {lit_str.span()=>
-					::#asteracea::lignin::Node::Text::<'bump, #thread_safety> {
+					#asteracea::lignin::Node::Text::<'bump, #thread_safety> {
 						text: #lit_str,
-						dom_binding: None, //TODO: Add text dom binding support.
+						dom_binding: #dom_binding,
 					}
 				}
 			}
 			Part::Html(html_definition) => html_definition.part_tokens(cx)?,
-			Part::If(InitMode::Dyn(_dyn_), _if_, _condition, _then_part, _else_, _else_part) => {
-				todo!("`dyn if`")
+			Part::If(
+				InitMode::Dyn(_dyn_, branches),
+				if_,
+				condition,
+				then_part,
+				else_,
+				else_part,
+			) => {
+				let asteracea = asteracea_path(if_.span);
+				let (then_branch, else_branch) = match branches.as_slice() {
+					[then_branch, else_branch] => (then_branch, else_branch),
+					_ => unreachable!("`dyn if` branch count"),
+				};
+				let then_tokens = then_branch.render_tokens(then_part.part_tokens(cx)?);
+				let leave_then = then_branch.leave_tokens();
+				let else_tokens = {
+					let else_part = else_branch.render_tokens(else_part.part_tokens(cx)?);
+					quote_spanned!(else_.span().resolved_at(Span::mixed_site())=> ::core::convert::identity( #else_part ))
+				};
+				let leave_else = else_branch.leave_tokens();
+				quote_spanned!(if_.span.resolved_at(Span::mixed_site())=> {
+					let if_: #asteracea::lignin::Node::<'bump, #thread_safety> = if #condition {
+						#leave_else
+						#then_tokens
+					} else {
+						#leave_then
+						#else_tokens
+					};
+					if_
+				})
 			}
 			Part::If(InitMode::Spread(_spread), if_, condition, then_part, else_, else_part) => {
-				let asteracea = asteracea_ident(if_.span);
+				let asteracea = asteracea_path(if_.span);
 				let then_tokens = then_part.part_tokens(cx)?;
 				let else_tokens = {
 					let else_part = else_part.part_tokens(cx)?;
 					quote_spanned!(else_.span().resolved_at(Span::mixed_site())=> ::core::convert::identity( #else_part ))
 				};
 				quote_spanned!(if_.span.resolved_at(Span::mixed_site())=> {
-					let if_: ::#asteracea::lignin::Node::<'bump, #thread_safety> = if #condition {
-						::#asteracea::lignin::auto_safety::Align::align(#then_tokens)
+					let if_: #asteracea::lignin::Node::<'bump, #thread_safety> = if #condition {
+						#asteracea::lignin::auto_safety::Align::align(#then_tokens)
 					} else {
-						::#asteracea::lignin::auto_safety::Align::align(#else_tokens)
+						#asteracea::lignin::auto_safety::Align::align(#else_tokens)
 					};
 					if_
 				})
 			}
-			Part::Match(InitMode::Dyn(_dyn_), _match_, _on, _bracket, _arms) => {
-				todo!("`dyn match`")
+			Part::Match(InitMode::Dyn(_dyn_, branches), match_, on, bracket, arms) => {
+				let asteracea = asteracea_path(match_.span);
+				let arms = arms
+					.iter()
+					.zip(branches)
+					.enumerate()
+					.map(|(i, ((attrs, pats, guard, fat_arrow, part), branch))| {
+						let guard = guard
+							.as_ref()
+							.map(|(if_, guard)| quote_spanned!(if_.span=> #if_ #guard));
+						let part = branch.render_tokens(part.part_tokens(cx)?);
+						let leave_others = branches
+							.iter()
+							.enumerate()
+							.filter(|(j, _)| *j != i)
+							.filter_map(|(_, other)| other.leave_tokens());
+						let (pipes, pats) = pats
+							.iter()
+							.map(|(pipe, pat)| (pipe.as_ref(), pat))
+							.unzip::<_, _, Vec<_>, Vec<_>>();
+						Ok(quote_spanned! {fat_arrow.span()=>
+							#(#attrs)*
+							#(#pipes #pats)* #guard #fat_arrow {
+								#(#leave_others)*
+								#part
+							}
+						})
+					})
+					.collect::<Result<Vec<_>>>()?;
+				let body = quote_spanned!(bracket.span=> { #(#arms)* });
+				quote_spanned!(match_.span.resolved_at(Span::mixed_site())=> {
+					let match_: #asteracea::lignin::Node::<'bump, #thread_safety> = #match_ #on #body;
+					match_
+				})
 			}
 			Part::Match(InitMode::Spread(_spread), match_, on, bracket, arms) => {
 				let arms = arms
@@ -472,17 +624,17 @@ impl<C: Configuration> Part<C> {
 				tokens
 			}
 			Part::Multi(bracket, m) => {
-				let asteracea = asteracea_ident(bracket.span.join());
+				let asteracea = asteracea_path(bracket.span.join());
 				let m = m
 					.iter()
 					.map(|part| part.part_tokens(cx))
 					.collect::<coreResult<Vec<_>, _>>()?;
 				let bump = Ident::new("bump", bracket.span.join().resolved_at(Span::call_site()));
 				quote_spanned! {bracket.span=>
-					::#asteracea::lignin::Node::Multi::<'bump, #thread_safety>(&*#bump.alloc_try_with(
-						|| -> ::std::result::Result::<_, ::#asteracea::error::Escalation> { ::std::result::Result::Ok([
+					#asteracea::lignin::Node::Multi::<'bump, #thread_safety>(&*#bump.alloc_try_with(
+						|| -> ::std::result::Result::<_, #asteracea::error::Escalation> { ::std::result::Result::Ok([
 							#(
-								::#asteracea::lignin::auto_safety::Align::align(#m),
+								#asteracea::lignin::auto_safety::Align::align(#m),
 							)*
 						])}
 					)?)
@@ -1,6 +1,6 @@
 use super::{GenerateContext, Part};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	part::LetSelf,
 	storage_configuration::{StorageConfiguration, StorageTypeConfiguration},
 	storage_context::{ParseContext, ParseWithContext},
@@ -59,7 +59,7 @@ impl<C: Configuration> ParseWithContext for AsteriskFor<C> {
 			.cloned()
 			.unwrap_or_else(|| cx.storage_context.next_field(for_.span));
 		let type_configuration = storage_configuration.type_configuration();
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -138,7 +138,7 @@ impl<C: Configuration> ParseWithContext for AsteriskFor<C> {
 
 impl<C: Configuration> AsteriskFor<C> {
 	pub fn part_tokens(&self, cx: &GenerateContext) -> Result<TokenStream> {
-		let asteracea = asteracea_ident(self.for_.span);
+		let asteracea = asteracea_path(self.for_.span);
 		let bump = Ident::new("bump", self.for_.span);
 
 		let Self {
@@ -169,9 +169,9 @@ impl<C: Configuration> AsteriskFor<C> {
 		quote_spanned!(for_span_mixed_site=> {
 			let asterisk_for = &this.#field_name;
 			let asterisk_for = &**asterisk_for;
-			let mut asterisk_for_items = ::#asteracea::bumpalo::vec![in #bump];
+			let mut asterisk_for_items = #asteracea::bumpalo::vec![in #bump];
 			#label #for_ #field_name #in_ asterisk_for.iter() #content
-			::#asteracea::lignin::Node::Multi(asterisk_for_items.into_bump_slice())
+			#asteracea::lignin::Node::Multi(asterisk_for_items.into_bump_slice())
 		})
 		.pipe(Ok)
 	}
@@ -1,6 +1,6 @@
 use super::{GenerateContext, LetSelf, Part};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	storage_configuration::{StorageConfiguration, StorageTypeConfiguration},
 	storage_context::{ParseContext, ParseWithContext},
 	workaround_module::Configuration,
@@ -38,7 +38,7 @@ impl<C: Configuration> ParseWithContext for Async<C> {
 
 		let type_configuration = storage_configuration.type_configuration();
 
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -60,15 +60,15 @@ impl<C: Configuration> ParseWithContext for Async<C> {
 			auto_generics,
 		);
 
-		let asteracea = asteracea_ident(async_.span);
+		let asteracea = asteracea_path(async_.span);
 		let node = quote_spanned!(async_.span.resolved_at(Span::call_site())=> node);
 		call2_strict(
 			quote_spanned! {async_.span=>
 				let #visibility self.#field_name =
-					pin ::#asteracea::include::async_::Async::<#type_path>
+					pin #asteracea::include::async_::Async::<#type_path>
 					::new(::std::boxed::Box::pin({
 						let #node = #node.clone_handle();
-						async move { ::#asteracea::error::Result::Ok(#storage_value) }
+						async move { #asteracea::error::Result::Ok(#storage_value) }
 					}));
 			},
 			|input| LetSelf::<C>::parse_with_context(input, cx),
@@ -113,14 +113,14 @@ impl<C: Configuration> Async<C> {
 		let bump = quote_spanned!(self.async_.span.resolved_at(Span::call_site())=> bump);
 		let content = self.content.part_tokens(cx)?;
 
-		let asteracea = asteracea_ident(self.async_.span);
+		let asteracea = asteracea_path(self.async_.span);
 		Ok(
 			quote_spanned! (self.async_.span.resolved_at(Span::mixed_site())=> {
 				this.#field_name_pinned().as_async_content(Box::new(|#bump| {
 					let this = this.#field_name_pinned().storage_pinned()?;
 					let #field_name = this.as_ref();
 					let this = #field_name;
-					::#asteracea::error::Result::Ok(#content)
+					#asteracea::error::Result::Ok(#content)
 				}))
 			}),
 		)
@@ -1,6 +1,6 @@
 use super::{GenerateContext, LetSelf, Part};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	storage_configuration::{StorageConfiguration, StorageTypeConfiguration},
 	storage_context::{ParseContext, ParseWithContext},
 	workaround_module::Configuration,
@@ -44,7 +44,7 @@ impl<C: Configuration> ParseWithContext for Bind<C> {
 
 		let type_configuration = storage_configuration.type_configuration();
 
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -68,15 +68,15 @@ impl<C: Configuration> ParseWithContext for Bind<C> {
 			auto_generics,
 		);
 
-		let asteracea = asteracea_ident(bind.span);
+		let asteracea = asteracea_path(bind.span);
 		let node = quote_spanned!(bind.span=> node);
 		call2_strict(
 			quote_spanned! {bind.span.resolved_at(Span::mixed_site())=>
-				let #visibility self.#field_name = pin ::#asteracea::try_lazy_init::LazyTransform::<
+				let #visibility self.#field_name = pin #asteracea::try_lazy_init::LazyTransform::<
 						#asteracea::__::rhizome::sync::NodeHandle<
 							::core::any::TypeId,
 							::core::any::TypeId,
-							::#asteracea::__::rhizome::sync::DynValue,
+							#asteracea::__::rhizome::sync::DynValue,
 						>,
 						#type_path,
 					>
@@ -121,7 +121,7 @@ impl<C: Configuration> ParseWithContext for Bind<C> {
 
 impl<C: Configuration> Bind<C> {
 	pub fn part_tokens(&self, cx: &GenerateContext) -> Result<TokenStream> {
-		let asteracea = asteracea_ident(self.bind.span);
+		let asteracea = asteracea_path(self.bind.span);
 		let field_name = &self.field_name;
 		let field_pinned = Ident::new(&format!("{}_pinned", field_name), field_name.span());
 		let node = quote_spanned!(self.bind.span=> node);
@@ -133,7 +133,7 @@ impl<C: Configuration> Bind<C> {
 			let #field_name = this.#field_pinned();
 			let #field_name = #field_name
 				.get_or_create_or_poison(
-					#move_ |#node| -> ::std::result::Result<_, ::#asteracea::error::Escalation> {
+					#move_ |#node| -> ::std::result::Result<_, #asteracea::error::Escalation> {
 						Ok(#binding_expression)
 					}
 				)
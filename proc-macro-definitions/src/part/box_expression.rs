@@ -36,7 +36,7 @@ impl<C: Configuration> ParseWithContext for BoxExpression<C> {
 
 		let type_configuration = storage_configuration.type_configuration();
 
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -1,8 +1,12 @@
 use std::{collections::HashSet, iter};
 
-use super::{GenerateContext, LetSelf};
+use super::{
+	event_binding::{kw as event_kw, EmittedEventBinding},
+	let_self::guesstimate_type,
+	GenerateContext, LetSelf,
+};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	part::Part,
 	storage_context::{ParseContext, ParseWithContext},
 	util::Braced,
@@ -17,7 +21,8 @@ use syn::{
 	spanned::Spanned,
 	token::{Brace, Eq, Paren, Question},
 	visit_mut::{visit_expr_mut, VisitMut},
-	Error, Expr, ExprPath, Ident, Label, Pat, PatIdent, PatTupleStruct, Result, Token, Visibility,
+	Error, Expr, ExprPath, Ident, Label, Pat, PatIdent, PatTupleStruct, Result, Token, Type,
+	Visibility,
 };
 use tap::Pipe;
 use unquote::unquote;
@@ -27,12 +32,14 @@ pub enum Component<C: Configuration> {
 		open_span: Span,
 		path: ExprPath,
 		capture: LetSelf<C>,
+		render_frame: TokenStream,
 		render_params: Vec<Parameter<Token![.]>>,
 		content_children: Vec<ContentChild<C>>,
 	},
 	Instanced {
 		open_span: Span,
 		reference: Braced,
+		render_frame: TokenStream,
 		render_params: Vec<Parameter<Token![.]>>,
 		content_children: Vec<ContentChild<C>>,
 	},
@@ -58,6 +65,13 @@ impl<C: Configuration> ParseWithContext for Component<C> {
 				render_params.push(param)
 			}
 
+			if input.peek(event_kw::on) {
+				return Err(Error::new(
+					input.span(),
+					"Event bindings on child component instances aren't supported, since the emitted types can't be named. Use a component path (`<*Name on event = …>`) instead.",
+				));
+			}
+
 			let content_children = parse_content_children(input, cx)?;
 
 			if input.peek(Token![>]) {
@@ -69,7 +83,9 @@ impl<C: Configuration> ParseWithContext for Component<C> {
 				));
 			}
 
+			let callee = format!("{{{}}}", compact(&reference.contents));
 			Ok(Self::Instanced {
+				render_frame: trace_frame::<C>(cx, &callee, "Render", open_span),
 				open_span,
 				reference,
 				render_params,
@@ -118,8 +134,22 @@ impl<C: Configuration> ParseWithContext for Component<C> {
 			}
 
 			let mut render_params: Vec<Parameter<Token![.]>> = vec![];
-			while input.peek(Token![.]) && input.peek2(Ident) {
-				render_params.push(input.parse()?)
+			loop {
+				if input.peek(Token![.]) && input.peek2(Ident) {
+					render_params.push(input.parse()?)
+				} else if input.peek(event_kw::on) {
+					// Emitted events are passed to the child as `.render` arguments.
+					let binding = EmittedEventBinding::parse_with_context(input, cx, &path)?;
+					render_params.push(Parameter {
+						punct: Token![.](binding.span()),
+						ident: binding.name().clone(),
+						question: None,
+						eq: Token![=](binding.span()),
+						value: binding.value(),
+					})
+				} else {
+					break;
+				}
 			}
 
 			let content_children = parse_content_children(input, cx)?;
@@ -155,11 +185,33 @@ impl<C: Configuration> ParseWithContext for Component<C> {
 				&[],
 			)?;
 
+			let asteracea = asteracea_path(Span::mixed_site());
+			let field_type = guesstimate_type(&parse2(quote!(#path::new))?)?;
+			let callee = compact(&path);
+			let new_frame = trace_frame::<C>(cx, &callee, "New", open_span);
+			let new_args = Ident::new("new_args", Span::mixed_site());
+			let new_call = quote_spanned!(open_span=> #path::new(node.as_ref(), #new_args));
+			let traced_new = if let Some(dot_await) = dot_await {
+				// Panics can't be caught across `.await`, so only escalated errors are traced here.
+				let result = Ident::new("result", Span::mixed_site());
+				quote! {{
+					let #new_args = #new_params;
+					let #result = #new_call #dot_await;
+					#asteracea::__::traced(#new_frame, move || #result)
+				}}
+			} else {
+				quote! {{
+					let #new_args = #new_params;
+					#asteracea::__::traced(#new_frame, || #new_call)
+				}}
+			};
+
 			Ok(Self::Instantiated {
+				render_frame: trace_frame::<C>(cx, &callee, "Render", open_span),
 				open_span,
 				capture: call2_strict(
 					quote_spanned! {open_span=>
-						let #visibility self.#field_name = pin #path::new(node.as_ref(), #new_params)#dot_await?;
+						let #visibility self.#field_name: #field_type = pin #traced_new?;
 					},
 					|input| LetSelf::<C>::parse_with_context(input, cx),
 				)
@@ -178,10 +230,13 @@ fn parse_content_children<C: Configuration>(
 	cx: &mut ParseContext,
 ) -> Result<Vec<ContentChild<C>>> {
 	let mut content_children = vec![];
+	let outer_content_child = cx.content_child;
 	while !input.peek(Token![/]) && !input.peek(Token![>]) {
+		cx.content_child = Some(input.span());
 		content_children.push(ContentChild {
 			slot: input.parse()?,
 			parent_parameters: parse_parent_parameters(input)?,
+			arguments: parse_slot_arguments(input)?,
 			part: loop {
 				if let Some(part) = Part::<C>::parse_with_context(input, cx)? {
 					break part;
@@ -189,6 +244,7 @@ fn parse_content_children<C: Configuration>(
 			},
 		})
 	}
+	cx.content_child = outer_content_child;
 	Ok(content_children)
 }
 
@@ -199,26 +255,27 @@ impl<C: Configuration> Component<C> {
 				open_span,
 				capture,
 				path,
+				render_frame,
 				render_params,
 				content_children,
 			} => {
-let render_call= {
-	let render_params = parameter_struct_expression(
-		Some(cx),
-		open_span.resolved_at(Span::mixed_site()),
-		parse2(quote_spanned! (open_span.resolved_at(Span::mixed_site())=> #path::render_args_builder())).expect("render_params make_builder 1"),
-		render_params.as_slice(),
-		content_children.as_slice(),
-	)?;
-	quote_spanned!(*open_span=> .render(bump, #render_params)?)
-};
-
-				let asteracea = asteracea_ident(Span::mixed_site());
+let render_params = parameter_struct_expression(
+	Some(cx),
+	open_span.resolved_at(Span::mixed_site()),
+	parse2(quote_spanned! (open_span.resolved_at(Span::mixed_site())=> #path::render_args_builder())).expect("render_params make_builder 1"),
+	render_params.as_slice(),
+	content_children.as_slice(),
+)?;
+let render_args = Ident::new("render_args", Span::mixed_site());
+let render_call = quote_spanned!(*open_span=> .render(bump, #render_args));
+
+				let asteracea = asteracea_path(Span::mixed_site());
 				let mut expr = parse2(quote!({
-					let rendered = #capture #render_call;
+					let #render_args = #render_params;
+					let rendered = #asteracea::__::traced(#render_frame, || #capture #render_call)?;
 
 					{
-						use ::#asteracea::lignin::auto_safety::{AutoSafe as _, Deanonymize as _};
+						use #asteracea::lignin::auto_safety::{AutoSafe as _, Deanonymize as _};
 						#[allow(deprecated)]
 						rendered.deanonymize()
 					}
@@ -230,10 +287,11 @@ let render_call= {
 			Component::Instanced {
 				open_span,
 				reference,
+				render_frame,
 				render_params,
 				content_children,
 			} => {
-				let asteracea = asteracea_ident(*open_span);
+				let asteracea = asteracea_path(*open_span);
 				let binding = quote_spanned!(reference.brace_token.span.join().resolved_at(Span::mixed_site())=> let reference: ::std::pin::Pin<&_> = #reference;);
 				let bump = quote_spanned!(*open_span=> bump);
 				let render_params = parameter_struct_expression(
@@ -247,10 +305,11 @@ let render_call= {
 				)?;
 				let mut expr = parse2(quote_spanned!(open_span.resolved_at(Span::mixed_site())=> {
 					#binding
-					let rendered = reference.render(#bump, #render_params)?;
+					let render_args = #render_params;
+					let rendered = #asteracea::__::traced(#render_frame, || reference.render(#bump, render_args))?;
 
 					{
-						use ::#asteracea::lignin::auto_safety::{AutoSafe as _, Deanonymize as _};
+						use #asteracea::lignin::auto_safety::{AutoSafe as _, Deanonymize as _};
 						#[allow(deprecated)]
 						rendered.deanonymize()
 					}
@@ -263,6 +322,29 @@ let render_call= {
 	}
 }
 
+/// An `asteracea::error::TraceFrame` expression locating the child component element at `open_span` in the current component,
+/// which calls `callee`'s `phase` method.
+fn trace_frame<C: Configuration>(
+	cx: &ParseContext,
+	callee: &str,
+	phase: &str,
+	open_span: Span,
+) -> TokenStream {
+	let asteracea = asteracea_path(Span::mixed_site());
+	let component = cx.component_name.map_or_else(
+		|| C::NAME.to_string(),
+		|name| name.to_string().trim_start_matches("r#").to_string(),
+	);
+	let phase = Ident::new(phase, Span::mixed_site());
+	let location = quote_spanned!(open_span=> ::core::file!(), ::core::line!(), ::core::column!());
+	quote!(#asteracea::error::TraceFrame::__Asteracea__new(#callee, #component, #asteracea::error::Phase::#phase, #location))
+}
+
+/// Formats `tokens` for display in a [`trace_frame`], without the spacing [`TokenStream`]'s [`Display`](`std::fmt::Display`) implementation adds.
+fn compact(tokens: &impl ToTokens) -> String {
+	tokens.to_token_stream().to_string().replace(' ', "")
+}
+
 //TODO: Find out why this is necessary and possibly a better solution.
 struct SelfMassager;
 impl VisitMut for SelfMassager {
@@ -302,21 +384,6 @@ impl<P: Parse> Parse for Parameter<P> {
 	}
 }
 
-impl<P: Spanned> ToTokens for Parameter<P> {
-	fn to_tokens(&self, tokens: &mut TokenStream) {
-		let value_stmts = &self.value.contents;
-		let value = quote_spanned! (self.value.brace_token.span.join().resolved_at(Span::mixed_site())=> {#value_stmts});
-		match self.question {
-			Some(_) => todo!("Conditional component parameters."),
-			None => {
-				let dot = quote_spanned!(self.punct.span()=> .);
-				let ident = &self.ident;
-				quote_spanned! (self.eq.span=> #dot #ident(#value)).to_tokens(tokens)
-			}
-		}
-	}
-}
-
 fn parameter_struct_expression<C: Configuration, P: Spanned>(
 	cx: Option<&GenerateContext>,
 	fallback_span: Span,
@@ -360,10 +427,6 @@ fn parameter_struct_expression<C: Configuration, P: Spanned>(
 				.build()
 		}
 	} else {
-		if !content_children.is_empty() {
-			todo!("Content children in the presence of optional parameters")
-		}
-
 		let mut deferred_names = HashSet::new();
 
 		let mut deferred = vec![];
@@ -400,6 +463,21 @@ fn parameter_struct_expression<C: Configuration, P: Spanned>(
 			})
 		}
 
+		// Only one arm runs, so each of them can take the content children, which are evaluated only once beforehand.
+		let content_children = content_children
+			.iter()
+			.enumerate()
+			.map(|(i, content_child)| {
+				let (span, slot, value) = content_child
+					.slot_and_value_tokens(cx.expect("`GenerateContent` is required here."))?;
+				let ident = Ident::new(&format!("content_child_{}", i), span);
+				output.extend(quote_spanned! {span=>
+					let #ident = #value;
+				});
+				Ok(quote_spanned!(span=> .#slot(#ident)))
+			})
+			.collect::<Result<Vec<_>>>()?;
+
 		let conditional_idents = deferred
 			.iter()
 			.filter(|deferred| deferred.conditional)
@@ -489,7 +567,7 @@ fn parameter_struct_expression<C: Configuration, P: Spanned>(
 					.map(|builder_call| (builder_call.builder_method, builder_call.deferred))
 					.unzip::<_, _, Vec<_>, Vec<_>>();
 				quote_spanned! {fallback_span.resolved_at(Span::mixed_site())=>
-					(#(#pats #commata )*) => builder #(.#method_names(#values))*.build(),
+					(#(#pats #commata )*) => builder #(.#method_names(#values))* #(#content_children)* .build(),
 				}
 			},
 		);
@@ -506,9 +584,17 @@ fn parameter_struct_expression<C: Configuration, P: Spanned>(
 pub struct ContentChild<C: Configuration> {
 	slot: Slot,
 	parent_parameters: Vec<Parameter<Token![->]>>,
+	arguments: Option<SlotArguments>,
 	part: Part<C>,
 }
 
+/// `|pat ⟦: Type⟧, …|` after a content child's parent parameters,
+/// for arguments that the child component passes when rendering the slot.
+struct SlotArguments {
+	open: Token![|],
+	arguments: Vec<(Pat, Option<(Token![:], Type)>)>,
+}
+
 enum Slot {
 	Anonymous(Span),
 	Named(Label),
@@ -526,6 +612,12 @@ impl Parse for Slot {
 
 impl<C: Configuration> ContentChild<C> {
 	fn parameter_tokens(&self, cx: &GenerateContext) -> Result<TokenStream> {
+		let (span, slot, value) = self.slot_and_value_tokens(cx)?;
+		Ok(quote_spanned!(span=> .#slot(#value)))
+	}
+
+	/// The builder method's span and name, and the slot's argument.
+	fn slot_and_value_tokens(&self, cx: &GenerateContext) -> Result<(Span, Ident, TokenStream)> {
 		let (span, slot) = match &self.slot {
 			Slot::Anonymous(span) => {
 				let span = span.resolved_at(Span::mixed_site());
@@ -537,12 +629,12 @@ impl<C: Configuration> ContentChild<C> {
 			),
 		};
 
-		let asteracea = asteracea_ident(span);
+		let asteracea = asteracea_path(span);
 
 		let parent_parameter_tokens = parameter_struct_expression::<C, Token![->]>(
 			Some(cx),
 			span,
-			parse_quote_spanned!(span=> ::#asteracea::__::infer_builder(phantom)),
+			parse_quote_spanned!(span=> #asteracea::__::infer_builder(phantom)),
 			&self.parent_parameters,
 			&[],
 		)?;
@@ -550,19 +642,33 @@ impl<C: Configuration> ContentChild<C> {
 
 		let bump = Ident::new("bump", span.resolved_at(Span::call_site()));
 		let bump_time = quote_spanned!(bump.span()=> 'bump);
+		let arguments = self.arguments.iter().flat_map(|arguments| {
+			arguments.arguments.iter().map(|(pat, ty)| match ty {
+				Some((colon, ty)) => quote!(#pat #colon #ty),
+				None => pat.to_token_stream(),
+			})
+		});
 		let part = match &self.part {
-			Part::Async(_) => part,
+			Part::Async(_) => match &self.arguments {
+				Some(arguments) => {
+					return Err(Error::new(
+						arguments.open.span,
+						"Slot arguments aren't supported on async content children.",
+					))
+				}
+				None => part,
+			},
 			_ => quote_spanned! {span=>
 				::std::boxed::Box::new(
-					|#bump: &#bump_time ::#asteracea::bumpalo::Bump| -> ::std::result::Result<_, ::#asteracea::error::Escalation> {
+					|#bump: &#bump_time #asteracea::bumpalo::Bump #(, #arguments)*| -> ::std::result::Result<_, #asteracea::error::Escalation> {
 						::core::result::Result::Ok(#part)
 					}
 				)
 			},
 		};
 
-		quote_spanned! {span=>
-			.#slot((
+		let value = quote_spanned! {span=>
+			(
 				{
 					// Many thanks to Yandros for help with the type inference here:
 					let phantom = [];
@@ -573,10 +679,9 @@ impl<C: Configuration> ContentChild<C> {
 					}
 				},
 				#part,
-			))
-		}
-		.to_token_stream()
-		.pipe(Ok)
+			)
+		};
+		Ok((span, slot, value))
 	}
 }
 
@@ -623,3 +728,24 @@ fn parse_parent_parameters(input: ParseStream) -> Result<Vec<Parameter<Token![->
 	}
 	Ok(parameters)
 }
+
+fn parse_slot_arguments(input: ParseStream) -> Result<Option<SlotArguments>> {
+	let open = match input.parse::<Option<Token![|]>>()? {
+		Some(open) => open,
+		None => return Ok(None),
+	};
+	let mut arguments = vec![];
+	while !input.peek(Token![|]) {
+		let pat = Pat::parse_single(input)?;
+		let ty = input
+			.parse::<Option<Token![:]>>()?
+			.map(|colon| Result::Ok((colon, input.parse()?)))
+			.transpose()?;
+		arguments.push((pat, ty));
+		if !input.peek(Token![|]) {
+			input.parse::<Token![,]>()?;
+		}
+	}
+	input.parse::<Token![|]>()?;
+	Ok(Some(SlotArguments { open, arguments }))
+}
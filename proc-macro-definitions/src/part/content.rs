@@ -3,26 +3,40 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use syn::{parse::ParseStream, spanned::Spanned, Ident, Result, Token};
 
-pub struct Content {
-	//TODO: For multi-use, accept `Token![...]`.
-	dot2: Token![..],
+pub enum Content {
+	/// Renders [`RenderOnce`](`::asteracea::include::render_callback::RenderOnce`) content.
+	Once(Token![..]),
+	/// Renders [`RenderMut`](`::asteracea::include::render_callback::RenderMut`) content, which may happen repeatedly.
+	Mut(Token![...]),
 }
 
 impl ParseWithContext for Content {
 	type Output = Self;
 
 	fn parse_with_context(input: ParseStream<'_>, _cx: &mut ParseContext) -> Result<Self::Output> {
-		Ok(Self {
-			dot2: input.parse()?,
+		Ok(if let Some(dot3) = input.parse().unwrap() {
+			Self::Mut(dot3)
+		} else {
+			Self::Once(input.parse()?)
 		})
 	}
 }
 
 impl Content {
 	pub fn part_tokens(&self) -> TokenStream {
-		let bump = Ident::new("bump", self.dot2.span());
-		quote_spanned! {self.dot2.span().resolved_at(Span::mixed_site())=>
-			(__Asteracea__anonymous_content.1)(#bump)?
+		match self {
+			Content::Once(dot2) => {
+				let bump = Ident::new("bump", dot2.span());
+				quote_spanned! {dot2.span().resolved_at(Span::mixed_site())=>
+					(__Asteracea__anonymous_content.1)(#bump)?
+				}
+			}
+			Content::Mut(dot3) => {
+				let bump = Ident::new("bump", dot3.span());
+				quote_spanned! {dot3.span().resolved_at(Span::mixed_site())=>
+					(&mut *__Asteracea__anonymous_content.1)(#bump)?
+				}
+			}
 		}
 	}
 }
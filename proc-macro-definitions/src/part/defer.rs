@@ -1,6 +1,6 @@
 use super::{GenerateContext, LetSelf, Part};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	storage_configuration::{StorageConfiguration, StorageTypeConfiguration},
 	storage_context::{ParseContext, ParseWithContext},
 	workaround_module::Configuration,
@@ -42,7 +42,7 @@ impl<C: Configuration> ParseWithContext for Defer<C> {
 
 		let type_configuration = storage_configuration.type_configuration();
 
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -64,11 +64,11 @@ impl<C: Configuration> ParseWithContext for Defer<C> {
 			auto_generics,
 		);
 
-		let asteracea = asteracea_ident(defer.span);
+		let asteracea = asteracea_path(defer.span);
 		let node = quote_spanned!(defer.span=> node);
 		call2_strict(
 			quote_spanned! {defer.span.resolved_at(Span::mixed_site())=>
-				let #visibility self.#field_name = pin ::#asteracea::include::Defer::<'static, #type_path>
+				let #visibility self.#field_name = pin #asteracea::include::Defer::<'static, #type_path>
 					::new(::std::boxed::Box::new({
 						#[allow(unused_variables)]
 						let #node = #node.clone_handle();
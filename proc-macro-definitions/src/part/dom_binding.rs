@@ -0,0 +1,145 @@
+use super::LetSelf;
+use crate::{asteracea_path, storage_context::ParseContext, workaround_module::Configuration};
+use call2_for_syn::call2_strict;
+use debugless_unwrap::DebuglessUnwrap;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote_spanned, ToTokens};
+use syn::{parse::ParseStream, Error, Ident, Result, Token, Visibility};
+
+/// `ref ⟦pub …⟧ self.name`
+pub struct DomBindingDefinition {
+	ref_: Token![ref],
+	access: TokenStream,
+}
+
+impl DomBindingDefinition {
+	/// Parses a DOM binding, iff the next token is `ref`.
+	///
+	/// `web_type` is the name of the bound node's type in `lignin::web`.
+	pub fn parse_with_context<C: Configuration>(
+		input: ParseStream<'_>,
+		cx: &mut ParseContext,
+		web_type: &str,
+	) -> Result<Option<Self>> {
+		let ref_: Token![ref] = match input.parse()? {
+			Some(ref_) => ref_,
+			None => return Ok(None),
+		};
+		if !C::CAN_CAPTURE {
+			return Err(Error::new(
+				ref_.span,
+				format!("DOM bindings are unavailable in this context: {}", C::NAME),
+			));
+		}
+		reject_in_content_child(cx, ref_.span, "DOM bindings (`ref`)")?;
+
+		let visibility: Visibility = input.parse()?;
+		let self_: Token![self] = input.parse()?;
+		let dot: Token![.] = input.parse()?;
+		let name: Ident = input.parse()?;
+
+		let access = Self::storage::<C>(
+			cx,
+			ref_.span,
+			quote_spanned!(ref_.span=> #visibility #self_ #dot #name),
+			web_type,
+		);
+
+		Ok(Some(Self { ref_, access }))
+	}
+
+	/// Creates a binding with hidden storage at `span`, for elements with property bindings (`.:property = …`) but no `ref`.
+	pub fn hidden<C: Configuration>(
+		cx: &mut ParseContext,
+		span: Span,
+		web_type: &str,
+	) -> Result<Self> {
+		if !C::CAN_CAPTURE {
+			return Err(Error::new(
+				span,
+				format!(
+					"Property bindings are unavailable in this context: {}",
+					C::NAME
+				),
+			));
+		}
+		reject_in_content_child(cx, span, "Property bindings (`.:…`)")?;
+
+		let name = cx.storage_context.next_field(span);
+		let access = Self::storage::<C>(
+			cx,
+			span,
+			quote_spanned!(span.resolved_at(Span::mixed_site())=> self.#name),
+			web_type,
+		);
+
+		Ok(Self {
+			ref_: Token![ref](span),
+			access,
+		})
+	}
+
+	/// Declares the `DomBinding` storage `target` (`⟦visibility⟧ self.name`) and returns its access expression.
+	fn storage<C: Configuration>(
+		cx: &mut ParseContext,
+		span: Span,
+		target: TokenStream,
+		web_type: &str,
+	) -> TokenStream {
+		let asteracea = asteracea_path(span);
+		let web_type = Ident::new(web_type, span);
+		call2_strict(
+			quote_spanned! {span.resolved_at(Span::mixed_site())=>
+				let #target = pin #asteracea::include::DomBinding::<
+					#asteracea::lignin::web::#web_type,
+				>::new();
+			},
+			|input| LetSelf::<C>::parse_with_context(input, cx),
+		)
+		.debugless_unwrap()
+		.expect("DOM binding let self")
+		.into_token_stream()
+	}
+
+	/// The pinned `DomBinding` storage.
+	pub fn access(&self) -> &TokenStream {
+		&self.access
+	}
+
+	/// The value of the bound node's `dom_binding` field.
+	pub fn binding_tokens(this: Option<&Self>, span: Span) -> TokenStream {
+		match this {
+			None => quote_spanned!(span=> ::core::option::Option::None),
+			Some(Self { ref_, access }) => {
+				let asteracea = asteracea_path(ref_.span);
+				quote_spanned! {ref_.span.resolved_at(Span::mixed_site())=>
+					::core::option::Option::Some({
+						use #asteracea::lignin::{
+							auto_safety::Align as _,
+							callback_registry::ToRefThreadBoundFallback as _,
+						};
+						#access.__Asteracea__registration().to_ref().align()
+					})
+				}
+			}
+		}
+	}
+}
+
+/// Errors iff `cx` is within a content child, as all renders of it would share one `DomBinding`.
+fn reject_in_content_child(cx: &ParseContext, span: Span, what: &str) -> Result<()> {
+	match cx.content_child {
+		None => Ok(()),
+		Some(content_child) => {
+			let mut error = Error::new(
+				span,
+				format!(
+					"{} aren't supported in content children, which may be rendered repeatedly",
+					what
+				),
+			);
+			error.combine(Error::new(content_child, "Content child starts here."));
+			Err(error)
+		}
+	}
+}
@@ -0,0 +1,168 @@
+use super::{LetSelf, Part};
+use crate::{
+	asteracea_path,
+	storage_configuration::StorageTypeConfiguration,
+	storage_context::{ParseContext, ParseWithContext},
+	workaround_module::Configuration,
+};
+use call2_for_syn::call2_strict;
+use debugless_unwrap::DebuglessUnwrap;
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::{
+	parse::{Parse, ParseStream},
+	Ident, Result, Visibility,
+};
+use tap::Pipe;
+
+pub mod kw {
+	syn::custom_keyword!(retain);
+	syn::custom_keyword!(drop);
+}
+
+/// ⟦retain‖drop⟧
+pub enum DropPolicy {
+	Drop(Option<kw::drop>),
+	Retain(kw::retain),
+}
+
+impl Parse for DropPolicy {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if let Some(retain) = input.parse().unwrap() {
+			Self::Retain(retain)
+		} else {
+			Self::Drop(input.parse().unwrap())
+		}
+		.pipe(Ok)
+	}
+}
+
+/// The storage of a single `dyn if` or `dyn match` branch.
+///
+/// Branches without captures don't generate a field.
+pub struct DynBranch {
+	span: Span,
+	policy: DropPolicy,
+	field_name: Option<Ident>,
+	storage_value: TokenStream,
+}
+
+impl DynBranch {
+	pub fn parse_with_context<C: Configuration>(
+		input: ParseStream<'_>,
+		cx: &mut ParseContext,
+		span: Span,
+		policy: DropPolicy,
+	) -> Result<(Self, Part<C>)> {
+		let field_name = cx.storage_context.next_field(span);
+		let type_configuration = StorageTypeConfiguration::Anonymous;
+		let nested_generics = cx.storage_generics.clone();
+
+		let mut parse_context = cx.new_nested(
+			cx.storage_context.generated_type_name(&field_name),
+			&nested_generics,
+		);
+		let content = Part::parse_required_with_context(input, &mut parse_context)?;
+
+		if parse_context
+			.storage_context
+			.field_definitions()
+			.next()
+			.is_none()
+		{
+			cx.assorted_items.extend(parse_context.assorted_items);
+			return Ok((
+				Self {
+					span,
+					policy,
+					field_name: None,
+					storage_value: TokenStream::new(),
+				},
+				content,
+			));
+		}
+
+		let type_path =
+			type_configuration.type_path(&cx.storage_context, &field_name, cx.storage_generics)?;
+
+		let storage_value = parse_context.storage_context.value(true, &type_path, true);
+
+		let asteracea = asteracea_path(span);
+		let node = quote_spanned!(span=> node);
+		call2_strict(
+			quote_spanned! {span.resolved_at(Span::mixed_site())=>
+				let self.#field_name = pin #asteracea::include::DynBranch::<#type_path>
+					::new(#node.clone_handle());
+			},
+			|input| LetSelf::<C>::parse_with_context(input, cx),
+		)
+		.debugless_unwrap()
+		.expect("dyn branch storage let self");
+
+		cx.assorted_items.extend(
+			type_configuration.struct_definition(
+				vec![],
+				Visibility::Inherited,
+				type_path
+					.path
+					.segments
+					.last()
+					.expect("`dyn` branch: generated storage type last segment")
+					.ident
+					.clone(),
+				&parse_context.storage_context,
+				cx.storage_generics,
+			)?,
+		);
+
+		cx.assorted_items.extend(parse_context.assorted_items);
+
+		Ok((
+			Self {
+				span,
+				policy,
+				field_name: Some(field_name),
+				storage_value,
+			},
+			content,
+		))
+	}
+
+	/// Tokens to run when a different branch of the same expression is taken.
+	pub fn leave_tokens(&self) -> Option<TokenStream> {
+		match (&self.field_name, &self.policy) {
+			(Some(field_name), DropPolicy::Drop(_)) => {
+				let field_pinned = Ident::new(&format!("{}_pinned", field_name), field_name.span());
+				Some(quote_spanned! {self.span.resolved_at(Span::mixed_site())=>
+					this.#field_pinned().clear();
+				})
+			}
+			(None, _) | (_, DropPolicy::Retain(_)) => None,
+		}
+	}
+
+	/// Wraps this branch's rendered `content` so that it is evaluated with the branch's storage as `this`.
+	pub fn render_tokens(&self, content: TokenStream) -> TokenStream {
+		let asteracea = asteracea_path(self.span);
+		let span = self.span.resolved_at(Span::mixed_site());
+		match &self.field_name {
+			None => quote_spanned! {span=>
+				#asteracea::lignin::auto_safety::Align::align(#content)
+			},
+			Some(field_name) => {
+				let field_pinned = Ident::new(&format!("{}_pinned", field_name), field_name.span());
+				let node = quote_spanned!(self.span=> node);
+				let storage_value = &self.storage_value;
+				quote_spanned! {span=>
+					this.#field_pinned().render_or_construct(
+						|#[allow(unused_variables)] #node| ::core::result::Result::Ok(#storage_value),
+						|#field_name| {
+							let this = #field_name;
+							::core::result::Result::Ok(#asteracea::lignin::auto_safety::Align::align(#content))
+						},
+					)?
+				}
+			}
+		}
+	}
+}
@@ -1,16 +1,17 @@
 use crate::{
-	asteracea_ident,
+	asteracea_path,
+	component_declaration::emitted_event_marker,
 	storage_context::ParseContext,
 	util::{Braced, SinglePat},
 };
 use proc_macro2::{Span, TokenStream};
 use quote::{quote_spanned, ToTokens};
 use syn::{
-	parenthesized,
+	bracketed, parenthesized,
 	parse::{Parse, ParseStream},
 	spanned::Spanned,
-	token::Paren,
-	Error, ExprPath, Ident, LitStr, Pat, Result, Token,
+	token::{Bracket, Paren},
+	Error, ExprPath, Ident, LitInt, LitStr, Pat, PathArguments, Result, Token, Type, TypePath,
 };
 use tap::Pipe as _;
 use unquote::unquote;
@@ -21,15 +22,23 @@ pub mod kw {
 	custom_keyword!(capture);
 	custom_keyword!(bubble);
 	custom_keyword!(active);
+	custom_keyword!(passive);
 	custom_keyword!(once);
+	custom_keyword!(prevent_default);
+	custom_keyword!(stop_propagation);
+	custom_keyword!(with);
 }
 
 pub struct EventBindingDefinition {
 	on: kw::on,
 	mode: EventMode,
+	prevent_default: Option<kw::prevent_default>,
+	stop_propagation: Option<kw::stop_propagation>,
 	name: EventName,
-	active: Option<kw::active>,
+	key_filter: Option<KeyFilter>,
+	passiveness: Passiveness,
 	once: Option<kw::once>,
+	payload: Option<Payload>,
 	handler: Handler,
 	component_name: Ident,
 	registration_field_name: Ident,
@@ -53,6 +62,190 @@ impl Parse for EventMode {
 	}
 }
 
+enum Passiveness {
+	None,
+	Active(kw::active),
+	Passive(kw::passive),
+}
+impl Parse for Passiveness {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if let Some(active) = input.parse().unwrap() {
+			Self::Active(active)
+		} else if let Some(passive) = input.parse().unwrap() {
+			Self::Passive(passive)
+		} else {
+			Self::None
+		}
+		.pipe(Ok)
+	}
+}
+
+/// `with {…}: Type`, a value stored alongside the callback registration on each render.
+struct Payload {
+	with: kw::with,
+	value: Braced,
+	payload_type: Type,
+}
+
+/// `[⟦Ctrl+⟧⟦Shift+⟧⟦Alt+⟧⟦Meta+⟧Key]` after the name of a keyboard event.
+struct KeyFilter {
+	bracket: Bracket,
+	ctrl: bool,
+	shift: bool,
+	alt: bool,
+	meta: bool,
+	/// The expected `KeyboardEvent.key` value, compared ASCII-case-insensitively.
+	key: String,
+}
+
+const KEYBOARD_EVENTS: &[&str] = &["keydown", "keyup", "keypress"];
+const MODIFIERS: &[&str] = &["Ctrl", "Shift", "Alt", "Meta"];
+/// Key names (other than letters, digits and function keys) that are matched literally, except for `Space`.
+const NAMED_KEYS: &[&str] = &[
+	"Enter",
+	"Escape",
+	"Tab",
+	"Space",
+	"Backspace",
+	"Delete",
+	"Insert",
+	"Home",
+	"End",
+	"PageUp",
+	"PageDown",
+	"ArrowUp",
+	"ArrowDown",
+	"ArrowLeft",
+	"ArrowRight",
+];
+
+impl Parse for KeyFilter {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let contents;
+		let bracket = bracketed!(contents in input);
+
+		let mut names = vec![];
+		loop {
+			let lookahead = contents.lookahead1();
+			if lookahead.peek(Ident) {
+				let ident: Ident = contents.parse()?;
+				names.push((ident.to_string(), ident.span()));
+			} else if lookahead.peek(LitInt) {
+				let digit: LitInt = contents.parse()?;
+				names.push((digit.to_string(), digit.span()));
+			} else {
+				return Err(lookahead.error());
+			}
+			if contents.is_empty() {
+				break;
+			}
+			contents.parse::<Token![+]>()?;
+		}
+
+		let (key, key_span) = names.pop().expect("at least one name");
+		let mut modifiers = vec![];
+		for (modifier, span) in names {
+			if !MODIFIERS.contains(&modifier.as_str()) {
+				return Err(Error::new(
+					span,
+					format!(
+						"Expected one of modifiers {}. (The key must come last.)",
+						quoted_list(MODIFIERS)
+					),
+				));
+			}
+			if modifiers.contains(&modifier) {
+				return Err(Error::new(
+					span,
+					format!("Duplicate modifier `{}`.", modifier),
+				));
+			}
+			modifiers.push(modifier);
+		}
+
+		let key = if MODIFIERS.contains(&key.as_str()) {
+			return Err(Error::new(
+				key_span,
+				format!("Expected a key after modifier `{}`.", key),
+			));
+		} else if let Some(key) = key_value(&key) {
+			key
+		} else {
+			return Err(Error::new(key_span, unrecognized_key_message(&key)));
+		};
+
+		let has = |modifier: &str| modifiers.iter().any(|m| m == modifier);
+		Ok(Self {
+			bracket,
+			ctrl: has("Ctrl"),
+			shift: has("Shift"),
+			alt: has("Alt"),
+			meta: has("Meta"),
+			key,
+		})
+	}
+}
+
+/// Maps a key name from a key filter to the matching `KeyboardEvent.key` value.
+fn key_value(name: &str) -> Option<String> {
+	if name.len() == 1 && name.chars().all(|c| c.is_ascii_alphanumeric()) {
+		Some(name.to_ascii_lowercase())
+	} else if name
+		.strip_prefix('F')
+		.and_then(|number| number.parse::<u8>().ok())
+		.map_or(false, |number| {
+			(1..=12).contains(&number) && !name.starts_with("F0")
+		}) {
+		Some(name.to_string())
+	} else if name == "Space" {
+		Some(" ".to_string())
+	} else {
+		NAMED_KEYS.contains(&name).then(|| name.to_string())
+	}
+}
+
+fn unrecognized_key_message(name: &str) -> String {
+	let lowercase = name.to_ascii_lowercase();
+	let prefix: String = lowercase.chars().take(3).collect();
+	let similar = NAMED_KEYS
+		.iter()
+		.copied()
+		.filter(|key| {
+			let key = key.to_ascii_lowercase();
+			key.starts_with(&prefix) || key.contains(&lowercase)
+		})
+		.collect::<Vec<_>>();
+	if similar.is_empty() {
+		format!(
+			"Unrecognized key name `{}`. Expected a letter (`A`–`Z`), a digit (`0`–`9`), `F1`–`F12` or one of {}.",
+			name,
+			quoted_list(NAMED_KEYS)
+		)
+	} else {
+		format!(
+			"Unrecognized key name `{}`. Did you mean {}?",
+			name,
+			quoted_list(&similar)
+		)
+	}
+}
+
+fn quoted_list(names: &[&str]) -> String {
+	match names {
+		[] => String::new(),
+		[name] => format!("`{}`", name),
+		[names @ .., last] => format!(
+			"{} or `{}`",
+			names
+				.iter()
+				.map(|name| format!("`{}`", name))
+				.collect::<Vec<_>>()
+				.join(", "),
+			last
+		),
+	}
+}
+
 enum EventName {
 	Known(Ident),
 	Custom(LitStr),
@@ -73,9 +266,9 @@ impl ToTokens for EventName {
 	fn to_tokens(&self, tokens: &mut TokenStream) {
 		match self {
 			EventName::Known(name) => {
-				let asteracea = asteracea_ident(name.span());
+				let asteracea = asteracea_path(name.span());
 				(quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
-					<dyn ::#asteracea::__::lignin_schema::events::#name::<_> as ::#asteracea::__::lignin_schema::EventInfo>::NAME
+					<dyn #asteracea::__::lignin_schema::events::#name::<_> as #asteracea::__::lignin_schema::EventInfo>::NAME
 				})
 				.to_tokens(tokens)
 			}
@@ -97,12 +290,14 @@ impl EventName {
 
 enum Handler {
 	Inline {
+		async_: Option<Token![async]>,
 		fn_: Token![fn],
 		handler_name: Option<Ident>,
 		paren: Paren,
 		self_: Token![self],
 		comma: Token![,],
 		event: Pat,
+		payload: Option<(Token![,], Pat)>,
 		body: Braced,
 	},
 	Predefined(ExprPath),
@@ -118,13 +313,63 @@ impl EventBindingDefinition {
 		unquote! {input,
 			#on
 			#let mode
+			#let prevent_default
+			#let stop_propagation
 			#name
+		};
+
+		let key_filter = if input.peek(Bracket) {
+			let key_filter: KeyFilter = input.parse()?;
+			if let EventName::Known(name) = &name {
+				if !KEYBOARD_EVENTS.contains(&name.to_string().as_str()) {
+					return Err(Error::new(
+						key_filter.bracket.span.join(),
+						format!(
+							"Key filters are only available on keyboard events ({}).",
+							quoted_list(KEYBOARD_EVENTS)
+						),
+					));
+				}
+			}
+			Some(key_filter)
+		} else {
+			None
+		};
+
+		unquote! {input,
 			=
-			#let active
+			#let passiveness
 			#let once
 		};
 
+		let payload = if let Some(with) = input.parse().unwrap() {
+			let value = input.parse()?;
+			input.parse::<Token![:]>()?;
+			Some(Payload {
+				with,
+				value,
+				payload_type: input.parse()?,
+			})
+		} else {
+			None
+		};
+
+		if let (Some(prevent_default), Passiveness::Passive(passive)) =
+			(&prevent_default, &passiveness)
+		{
+			let mut error = Error::new(
+				passive.span,
+				"Keyword `passive` can't be combined with `prevent_default`.",
+			);
+			error.combine(Error::new(
+				prevent_default.span,
+				"Passive event listeners can't prevent the default action.",
+			));
+			return Err(error);
+		}
+
 		let handler: Handler = {
+			let async_: Option<Token![async]> = input.parse().unwrap();
 			if let Some(fn_) = input.parse().unwrap() {
 				let handler_name = input.parse()?;
 				let args_list;
@@ -135,19 +380,39 @@ impl EventBindingDefinition {
 					#let comma
 					#event
 				};
+				let payload = if payload.is_some() {
+					let comma = args_list.parse().map_err(|error| {
+						Error::new(error.span(), "Expected `,` followed by the payload pattern")
+					})?;
+					Some((comma, args_list.parse::<SinglePat>()?.pat))
+				} else {
+					None
+				};
 				if !args_list.is_empty() {
 					return Err(Error::new(args_list.span(), "Unexpected token"));
 				}
 				let body = input.parse()?;
+				if let Some(async_) = async_ {
+					if cx.async_event_handlers.get().is_none() {
+						cx.async_event_handlers.set(Some(async_.span));
+					}
+				}
 				Handler::Inline {
+					async_,
 					fn_,
 					handler_name,
 					paren,
 					self_,
 					comma,
 					event: event.pat,
+					payload,
 					body,
 				}
+			} else if let Some(async_) = async_ {
+				return Err(Error::new(
+					async_.span,
+					"Expected `fn`: Only inline event handlers can be `async`.",
+				));
 			} else {
 				Handler::Predefined(input.parse().map_err(|error| {
 					Error::new(error.span(), "Expected `fn` or path of event handler")
@@ -165,6 +430,19 @@ impl EventBindingDefinition {
 			})?
 			.clone();
 
+		if cx.dom_events.get().is_none() {
+			if let Some(span) = prevent_default
+				.map(|prevent_default| prevent_default.span)
+				.or_else(|| stop_propagation.map(|stop_propagation| stop_propagation.span))
+				.or_else(|| {
+					key_filter
+						.as_ref()
+						.map(|key_filter| key_filter.bracket.span.join())
+				}) {
+				cx.dom_events.set(Some(span));
+			}
+		}
+
 		let registration_field_name = Ident::new(
 			&format!(
 				"__Asteracea__event_binding_{}_on_{}_{}",
@@ -193,88 +471,265 @@ impl EventBindingDefinition {
 			on.span.resolved_at(Span::mixed_site()),
 		);
 
-		let asteracea = asteracea_ident(on.span);
+		let asteracea = asteracea_path(on.span);
 		cx.callback_registrations.borrow_mut().push((
 			registration_field_name.clone(),
 			syn::parse2(quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
-				::#asteracea::lignin::web::Event
+				#asteracea::lignin::web::Event
 			})
 			.expect("event binding parameter type"),
+			payload.as_ref().map(|payload| payload.payload_type.clone()),
 		));
 
 		Ok(EventBindingDefinition {
 			on,
 			mode,
+			prevent_default,
+			stop_propagation,
 			name,
-			active,
+			key_filter,
+			passiveness,
 			once,
+			payload,
 			handler,
 			component_name,
 			registration_field_name,
 		})
 	}
 
+	/// The event's name, iff it is validated against `lignin_schema`.
+	pub fn known_name(&self) -> Option<&Ident> {
+		match &self.name {
+			EventName::Known(name) => Some(name),
+			EventName::Custom(_) => None,
+		}
+	}
+
 	pub fn part_tokens(&self) -> TokenStream {
 		let EventBindingDefinition {
 			on,
 			mode,
+			prevent_default,
+			stop_propagation,
 			name,
-			active,
+			key_filter,
+			passiveness,
 			once,
+			payload,
 			handler,
 			component_name,
 			registration_field_name,
 		} = self;
-		let asteracea = asteracea_ident(on.span);
+		let asteracea = asteracea_path(on.span);
 		let self_ = quote_spanned!(on.span=> self);
 
-		let handler = match handler {
-			Handler::Inline {
-				fn_,
-				handler_name,
-				paren,
-				self_,
-				comma,
-				event,
-				body,
-			} => {
+		let event_type = quote_spanned!(on.span.resolved_at(Span::mixed_site())=> #asteracea::lignin::web::Event);
+		let payload_type = payload.as_ref().map(|payload| &payload.payload_type);
+		let handler = match (handler, payload_type) {
+			(
+				Handler::Inline {
+					async_,
+					fn_,
+					handler_name,
+					paren,
+					self_,
+					comma,
+					event,
+					payload,
+					body,
+				},
+				payload_type,
+			) => {
 				let handler_name = handler_name.as_ref().cloned().unwrap_or_else(|| {
 					let mut handler_name = registration_field_name.clone();
 					handler_name.set_span(fn_.span.resolved_at(Span::mixed_site()));
 					handler_name
 				});
 
-				let args = quote_spanned! {paren.span.join().resolved_at(Span::mixed_site())=>
-					(#self_: ::std::pin::Pin<&Self>#comma #event: ::#asteracea::lignin::web::Event)
+				let (args, payload_param, payload_arg) = match (payload, payload_type) {
+					(Some((payload_comma, payload)), Some(payload_type)) => (
+						quote_spanned! {paren.span.join().resolved_at(Span::mixed_site())=>
+							(#self_: ::std::pin::Pin<&Self>#comma #event: #event_type #payload_comma #payload: #payload_type)
+						},
+						Some(
+							quote_spanned!(payload_comma.span.resolved_at(Span::mixed_site())=> , payload: #payload_type),
+						),
+						Some(
+							quote_spanned!(payload_comma.span.resolved_at(Span::mixed_site())=> , payload),
+						),
+					),
+					(None, None) => (
+						quote_spanned! {paren.span.join().resolved_at(Span::mixed_site())=>
+							(#self_: ::std::pin::Pin<&Self>#comma #event: #event_type)
+						},
+						None,
+						None,
+					),
+					_ => unreachable!(
+						"Inline handler payload parameters are parsed iff there is a payload."
+					),
 				};
-				quote_spanned!(fn_.span.resolved_at(Span::mixed_site())=> {
-					impl #component_name {
-						fn #handler_name #args #body
-					}
 
-					unsafe {
-						//SAFETY: Defined with a compatible signature directly above.
-						// `Pin` is transparent, `&Self` is compatible with `*const Self` for valid pointers.
-						::std::mem::transmute(Self::#handler_name as fn(_, _))
-					}
-				})
+				let (definitions, handler_name) = if let Some(async_) = async_ {
+					let spawn_name = Ident::new(
+						&format!(
+							"{}_spawn",
+							handler_name.to_string().trim_start_matches("r#")
+						),
+						handler_name.span().resolved_at(Span::mixed_site()),
+					);
+					let event_tasks = Ident::new(
+						"__Asteracea__event_tasks",
+						async_.span.resolved_at(Span::mixed_site()),
+					);
+					(
+						quote_spanned! {async_.span.resolved_at(Span::mixed_site())=>
+							#async_ fn #handler_name #args #body
+
+							fn #spawn_name(this: ::std::pin::Pin<&Self>, event: #event_type #payload_param) {
+								let future = Self::#handler_name(this, event #payload_arg);
+								unsafe {
+									//SAFETY: Event tasks are dropped before any other part of the component,
+									// except for its callback registrations (which are dropped first so that no new tasks are started).
+									this.#event_tasks.spawn(future)
+								}
+							}
+						},
+						spawn_name,
+					)
+				} else {
+					(
+						quote_spanned!(fn_.span.resolved_at(Span::mixed_site())=> fn #handler_name #args #body),
+						handler_name,
+					)
+				};
+
+				if payload_type.is_some() {
+					quote_spanned!(fn_.span.resolved_at(Span::mixed_site())=> {
+						impl #component_name {
+							#definitions
+						}
+
+						Self::#handler_name as fn(_, _, _)
+					})
+				} else {
+					quote_spanned!(fn_.span.resolved_at(Span::mixed_site())=> {
+						impl #component_name {
+							#definitions
+						}
+
+						unsafe {
+							//SAFETY: Defined with a compatible signature directly above.
+							// `Pin` is transparent, `&Self` is compatible with `*const Self` for valid pointers.
+							::std::mem::transmute(Self::#handler_name as fn(_, _))
+						}
+					})
+				}
 			}
-			Handler::Predefined(predefined) => {
+			(Handler::Predefined(predefined), Some(payload_type)) => {
 				quote_spanned!(predefined.span().resolved_at(Span::mixed_site())=> {
-					// Deny using component state, since this isn't evaluated more than once.
-					let _: fn() = || {
-						// Make sure the signature matches
-						let _: &dyn ::#asteracea::__::CallbackHandler::<Self, ::#asteracea::lignin::web::Event, _> = &#predefined;
+					let handler: fn(::std::pin::Pin<&Self>, #event_type, #payload_type) = #predefined;
+					handler
+				})
+			}
+			(Handler::Predefined(predefined), None) => {
+				predefined_handler(&asteracea, predefined, &event_type)
+			}
+		};
+
+		let has_modifiers = prevent_default.is_some() || stop_propagation.is_some();
+		let handler = if has_modifiers || key_filter.is_some() {
+			let wrapper_name = Ident::new(
+				&format!("{}_with_modifiers", registration_field_name),
+				on.span.resolved_at(Span::mixed_site()),
+			);
+			let modifiers = has_modifiers.then(|| {
+				let prevent_default = prevent_default.is_some();
+				let stop_propagation = stop_propagation.is_some();
+				quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
+					const MODIFIERS: #asteracea::services::EventModifiers = #asteracea::services::EventModifiers {
+						prevent_default: #prevent_default,
+						stop_propagation: #stop_propagation,
 					};
-					// Make sure it's a function, not a closure
-					let handler: fn(_, _) = #predefined;
-					unsafe {
-						// SAFETY: This is validated to be
-						// - signature-matching (via the trait implementation)
-						// - not a closure (via the coercion above)
-						::std::mem::transmute(handler)
+					#asteracea::services::DomEvents::apply_modifiers(dom_events, &event, MODIFIERS);
+				}
+			});
+			let key_filter = key_filter.as_ref().map(
+				|KeyFilter {
+				     bracket,
+				     ctrl,
+				     shift,
+				     alt,
+				     meta,
+				     key,
+				 }| {
+					quote_spanned! {bracket.span.join().resolved_at(Span::mixed_site())=>
+						if !#asteracea::__::KeyFilter {
+							key: #key,
+							ctrl: #ctrl,
+							shift: #shift,
+							alt: #alt,
+							meta: #meta,
+						}.matches(dom_events, &event) {
+							return;
+						}
 					}
-				})
+				},
+			);
+			let wrapper = if let Some(payload_type) = payload_type {
+				quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
+					fn #wrapper_name(this: ::std::pin::Pin<&Self>, event: #event_type, payload: #payload_type) {
+						let dom_events: &dyn #asteracea::services::DomEvents = &*this.__Asteracea__dom_events;
+						#key_filter
+						#modifiers
+						let handler: fn(::std::pin::Pin<&Self>, #event_type, #payload_type) = #handler;
+						handler(this, event, payload)
+					}
+				}
+			} else {
+				quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
+					fn #wrapper_name(this: *const Self, event: #event_type) {
+						let dom_events: &dyn #asteracea::services::DomEvents = &*unsafe {
+							//SAFETY: Callback registrations are dropped before the rest of the component, so `this` is valid here.
+							&*this
+						}
+						.__Asteracea__dom_events;
+						#key_filter
+						#modifiers
+						let handler: fn(*const Self, #event_type) = #handler;
+						handler(this, event)
+					}
+				}
+			};
+			let cast = if payload_type.is_some() {
+				quote_spanned!(on.span.resolved_at(Span::mixed_site())=> fn(_, _, _))
+			} else {
+				quote_spanned!(on.span.resolved_at(Span::mixed_site())=> fn(_, _))
+			};
+			quote_spanned!(on.span.resolved_at(Span::mixed_site())=> {
+				impl #component_name {
+					// Key filters and event modifiers are applied before the handler runs.
+					#wrapper
+				}
+
+				Self::#wrapper_name as #cast
+			})
+		} else {
+			handler
+		};
+
+		let registration = if let Some(Payload { with, value, .. }) = payload {
+			quote_spanned! {with.span.resolved_at(Span::mixed_site())=>
+				let registration = #self_.#registration_field_name.claim(#self_, #handler, #value);
+			}
+		} else {
+			quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
+				let registration = #self_.#registration_field_name.get_or_create(|| {
+					#asteracea::lignin::CallbackRegistration::<Self, fn(#event_type)>::new(
+						#self_,
+						#handler,
+					)
+				});
 			}
 		};
 
@@ -286,8 +741,8 @@ impl EventBindingDefinition {
 			match mode {
 				EventMode::None => {
 					quote_spanned!(name.span().resolved_at(Span::mixed_site())=> {
-						use ::#asteracea::__::lignin_schema::{EventInfo, YesNo};
-						const #const_name: () = if <dyn ::#asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
+						use #asteracea::__::lignin_schema::{EventInfo, YesNo};
+						const #const_name: () = if <dyn #asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
 							panic!("Expected one of keywords `bubble` or `capture`, as this event bubbles.")
 						};
 					})
@@ -300,8 +755,8 @@ impl EventBindingDefinition {
 						#const_name()
 					};
 					quote_spanned!(name.span().resolved_at(Span::mixed_site())=> {
-						use ::#asteracea::__::lignin_schema::{EventInfo, YesNo};
-						const #const_name: () = if !<dyn ::#asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
+						use #asteracea::__::lignin_schema::{EventInfo, YesNo};
+						const #const_name: () = if !<dyn #asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
 							#panic
 						};
 					})
@@ -314,8 +769,8 @@ impl EventBindingDefinition {
 						#const_name()
 					};
 					quote_spanned!(name.span().resolved_at(Span::mixed_site())=> {
-						use ::#asteracea::__::lignin_schema::{EventInfo, YesNo};
-						const #const_name: () = if !<dyn ::#asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
+						use #asteracea::__::lignin_schema::{EventInfo, YesNo};
+						const #const_name: () = if !<dyn #asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
 							#panic
 						};
 					})
@@ -332,7 +787,11 @@ impl EventBindingDefinition {
 		};
 
 		let validate_active = if let EventName::Known(name) = name {
-			active.map(|active| {
+			match passiveness {
+				Passiveness::Active(active) => Some(active),
+				Passiveness::None | Passiveness::Passive(_) => None,
+			}
+			.map(|active| {
 				let const_name = Ident::new(
 					&name.to_string(),
 					name.span().resolved_at(Span::mixed_site()),
@@ -344,8 +803,8 @@ impl EventBindingDefinition {
 					#const_name()
 				};
 				quote_spanned!(name.span().resolved_at(Span::mixed_site())=> {
-					use ::#asteracea::__::lignin_schema::{EventInfo, YesNo};
-						const #const_name: () = if !<dyn ::#asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
+					use #asteracea::__::lignin_schema::{EventInfo, YesNo};
+					const #const_name: () = if !<dyn #asteracea::__::lignin_schema::events::#name as EventInfo>::Bubbles::IS_YES {
 						#panic
 					};
 				})
@@ -353,25 +812,28 @@ impl EventBindingDefinition {
 		} else {
 			None
 		};
-		let active = active.map(|active| quote_spanned!(active.span=> .with_passive(false)));
+		let passiveness = match passiveness {
+			// Passive listeners can't cancel the event, so `prevent_default` registers an active one.
+			Passiveness::None => prevent_default
+				.map(|prevent_default| quote_spanned!(prevent_default.span=> .with_passive(false))),
+			Passiveness::Active(active) => Some(quote_spanned!(active.span=> .with_passive(false))),
+			Passiveness::Passive(passive) => {
+				Some(quote_spanned!(passive.span=> .with_passive(true)))
+			}
+		};
 
 		let once = once.map(|once| quote_spanned!(once.span=> .with_once(true)));
 
 		quote_spanned!(on.span.resolved_at(Span::mixed_site())=> {
 			#validate_active
 			#validate_mode
-			let registration = #self_.#registration_field_name.get_or_create(|| {
-				::#asteracea::lignin::CallbackRegistration::<Self, fn(::#asteracea::lignin::web::Event)>::new(
-					#self_,
-					#handler,
-				)
-			});
+			#registration
 
-			::#asteracea::lignin::EventBinding {
+			#asteracea::lignin::EventBinding {
 				name: #name,
-				options: ::#asteracea::lignin::EventBindingOptions::new()#active #once #mode,
+				options: #asteracea::lignin::EventBindingOptions::new()#passiveness #once #mode,
 				callback: {
-					use ::#asteracea::lignin::{
+					use #asteracea::lignin::{
 						auto_safety::Align as _,
 						callback_registry::ToRefThreadBoundFallback as _,
 					};
@@ -381,3 +843,159 @@ impl EventBindingDefinition {
 		})
 	}
 }
+
+/// Validates a predefined handler against `CallbackHandler` and converts it to `fn(*const Self, parameter_type)`.
+fn predefined_handler(
+	asteracea: &TokenStream,
+	predefined: &ExprPath,
+	parameter_type: &TokenStream,
+) -> TokenStream {
+	quote_spanned!(predefined.span().resolved_at(Span::mixed_site())=> {
+		// Deny using component state, since this isn't evaluated more than once.
+		let _: fn() = || {
+			// Make sure the signature matches
+			let _: &dyn #asteracea::__::CallbackHandler::<Self, #parameter_type, _> = &#predefined;
+		};
+		// Make sure it's a function, not a closure
+		let handler: fn(_, _) = #predefined;
+		unsafe {
+			// SAFETY: This is validated to be
+			// - signature-matching (via the trait implementation)
+			// - not a closure (via the coercion above)
+			::std::mem::transmute(handler)
+		}
+	})
+}
+
+/// `on name = handler` on a child component element (`<*Child on name = handler>`),
+/// which binds an event the child declared with `emits name: Type,`.
+pub struct EmittedEventBinding {
+	on: kw::on,
+	name: Ident,
+	handler: ExprPath,
+	payload_type: Type,
+	registration_field_name: Ident,
+}
+
+impl EmittedEventBinding {
+	pub fn parse_with_context(
+		input: ParseStream<'_>,
+		cx: &mut ParseContext,
+		child: &ExprPath,
+	) -> Result<Self> {
+		let on: kw::on;
+		let name: Ident;
+		unquote! {input,
+			#on
+			#name
+			=
+		};
+
+		if input.peek(Token![fn]) || input.peek(Token![async]) {
+			return Err(Error::new(
+				input.span(),
+				"Expected path of event handler. (Child component events don't support inline handlers.)",
+			));
+		}
+		let handler: ExprPath = input
+			.parse()
+			.map_err(|error| Error::new(error.span(), "Expected path of event handler"))?;
+
+		if cx.component_name.is_none() {
+			return Err(Error::new(
+				on.span,
+				"Event bindings are only available within full components.",
+			));
+		}
+
+		// The child declares a marker type for each emitted event next to itself,
+		// and implements `Emits<Marker>` to name the payload type (which may depend on its generics).
+		let mut marker_path = child.path.clone();
+		let last = marker_path.segments.last_mut().ok_or_else(|| {
+			Error::new_spanned(
+				child,
+				"Expected child component path to have a last segment.",
+			)
+		})?;
+		last.ident = emitted_event_marker(&last.ident, &name);
+		last.arguments = PathArguments::None;
+		let marker = Type::Path(TypePath {
+			qself: child.qself.clone(),
+			path: marker_path,
+		});
+		let child_type = Type::Path(TypePath {
+			qself: child.qself.clone(),
+			path: child.path.clone(),
+		});
+		let asteracea = asteracea_path(on.span);
+		let payload_type: Type =
+			syn::parse2(quote_spanned! {on.span.resolved_at(Span::mixed_site())=>
+				<#child_type as #asteracea::__::Emits<#marker>>::Payload
+			})
+			.expect("emitted event payload type");
+
+		let registration_field_name = Ident::new(
+			&format!(
+				"__Asteracea__emitted_event_binding_{}_on_{}",
+				cx.callback_registrations.borrow().len(),
+				name.to_string().trim_start_matches("r#"),
+			),
+			on.span.resolved_at(Span::mixed_site()),
+		);
+		cx.callback_registrations.borrow_mut().push((
+			registration_field_name.clone(),
+			payload_type.clone(),
+			None,
+		));
+
+		Ok(Self {
+			on,
+			name,
+			handler,
+			payload_type,
+			registration_field_name,
+		})
+	}
+
+	pub fn span(&self) -> Span {
+		self.on.span
+	}
+
+	/// The name of the emitted event, which is also the name of the child's matching `.render` argument.
+	pub fn name(&self) -> &Ident {
+		&self.name
+	}
+
+	/// The `Emitter` passed to the child as `.render` argument.
+	pub fn value(&self) -> Braced {
+		let EmittedEventBinding {
+			on,
+			name: _,
+			handler,
+			payload_type,
+			registration_field_name,
+		} = self;
+		let asteracea = asteracea_path(on.span);
+		let self_ = quote_spanned!(on.span=> self);
+		let payload_type = payload_type.to_token_stream();
+		let handler = predefined_handler(&asteracea, handler, &payload_type);
+
+		syn::parse2(quote_spanned! {on.span.resolved_at(Span::mixed_site())=> {
+			let registration = #self_.#registration_field_name.get_or_create(|| {
+				#asteracea::lignin::CallbackRegistration::<Self, fn(#payload_type)>::new(
+					#self_,
+					#handler,
+				)
+			});
+
+			#asteracea::include::Emitter::new({
+				use #asteracea::lignin::{
+					auto_safety::Align as _,
+					callback_registry::ToRefThreadBoundFallback as _,
+				};
+				registration.to_ref().align()
+			})
+		}})
+		.expect("emitted event binding value")
+	}
+}
@@ -1,6 +1,6 @@
 use super::{GenerateContext, Part};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	part::LetSelf,
 	storage_configuration::{StorageConfiguration, StorageTypeConfiguration},
 	storage_context::{ParseContext, ParseWithContext},
@@ -82,7 +82,7 @@ impl<C: Configuration> ParseWithContext for For<C> {
 			.cloned()
 			.unwrap_or_else(|| cx.storage_context.next_field(for_.span));
 		let type_configuration = storage_configuration.type_configuration();
-		let nested_generics = type_configuration.generics()?;
+		let nested_generics = type_configuration.generics(cx.storage_generics)?;
 		let auto_generics = nested_generics.is_none();
 		let nested_generics = nested_generics.unwrap_or_else(|| cx.storage_generics.clone());
 
@@ -108,7 +108,7 @@ impl<C: Configuration> ParseWithContext for For<C> {
 			auto_generics,
 		);
 
-		let asteracea = asteracea_ident(for_.span);
+		let asteracea = asteracea_path(for_.span);
 		let node = quote_spanned!(for_.span=> node);
 
 		let k = if let Some((_, key_type)) = &key_type {
@@ -117,15 +117,15 @@ impl<C: Configuration> ParseWithContext for For<C> {
 			type_.as_ref().map(|(colon, type_): &(_, Type)| {
 				let type_ = make_type_static(type_.clone());
 				quote_spanned! {colon.span.resolved_at(Span::mixed_site())=>
-					<<#type_ as ::#asteracea::__::UnBorrow>::Target as ::std::borrow::ToOwned>::Owned
+					<<#type_ as #asteracea::__::UnBorrow>::Target as ::std::borrow::ToOwned>::Owned
 				}
 			})
 		}
 		.into_iter();
 		call2_strict(
 			quote_spanned! {for_.span.resolved_at(Span::mixed_site())=>
-				let #visibility self.#field_name = ::core::cell::RefCell::<::#asteracea::include::For::<'static, #type_path #(, #k)*>>::new(
-					::#asteracea::include::For::new({
+				let #visibility self.#field_name = ::core::cell::RefCell::<#asteracea::include::For::<'static, #type_path #(, #k)*>>::new(
+					#asteracea::include::For::new({
 						#[allow(unused_variables)]
 						let #node = #node.clone_handle();
 						move || Ok(#manufactured_item_state)
@@ -189,7 +189,7 @@ fn make_type_static(mut type_: Type) -> Type {
 
 impl<C: Configuration> For<C> {
 	pub fn part_tokens(&self, cx: &GenerateContext) -> Result<TokenStream> {
-		let asteracea = asteracea_ident(self.for_.span);
+		let asteracea = asteracea_path(self.for_.span);
 		let bump = Ident::new("bump", self.for_.span);
 
 		let Self {
@@ -212,15 +212,15 @@ impl<C: Configuration> For<C> {
 				quote_spanned! {keyed.span.resolved_at(Span::mixed_site())=>
 					// There is really nothing to go on here. Use dynamically typed keys.
 					|#pat| ::core::result::Result::Ok(
-						::#asteracea::include::__for_::InferredQ::from_ref(#key)
+						#asteracea::include::__for_::InferredQ::from_ref(#key)
 					)
 				}
 			}
 			(None, None, None) => quote_spanned! {for_span_mixed_site=>
 				// There is really nothing to go on here. Use dynamically typed keys.
 				|item: &mut _| ::core::result::Result::Ok(
-					::#asteracea::include::__for_::InferredQ::from_ref(
-						::#asteracea::__::UnBorrow::one_borrow(item)
+					#asteracea::include::__for_::InferredQ::from_ref(
+						#asteracea::__::UnBorrow::one_borrow(item)
 					)
 				)
 			},
@@ -231,7 +231,7 @@ impl<C: Configuration> For<C> {
 			}
 			(Some(_), _, _) => quote_spanned! {for_span_mixed_site=>
 				// There is a key type, so infer/derive forwards towards Q:
-				|item: &mut _| ::core::result::Result::Ok(::#asteracea::__::UnBorrow::one_borrow(item))
+				|item: &mut _| ::core::result::Result::Ok(#asteracea::__::UnBorrow::one_borrow(item))
 			},
 			(None, _, Some(_)) => quote_spanned! {for_span_mixed_site=>
 				//Help Wanted: This is necessary to resolve e.g. `for i => u8 in &[1, 2, 3, 4, 5]` "backwards",
@@ -242,7 +242,7 @@ impl<C: Configuration> For<C> {
 
 		let generics = type_.as_ref().map(|(colon, type_)| {
 			quote_spanned! {colon.span.resolved_at(Span::mixed_site())=>
-				::<_, <#type_ as ::#asteracea::__::UnBorrow>::Target, _, _>
+				::<_, <#type_ as #asteracea::__::UnBorrow>::Target, _, _>
 			}
 		});
 
@@ -257,7 +257,7 @@ impl<C: Configuration> For<C> {
 			let (#pat, reorderable_storage)#item_type = item?;
 			let #field_name = reorderable_storage.as_ref().storage();
 			let this = #field_name;
-			for_items.push(::#asteracea::lignin::ReorderableFragment {
+			for_items.push(#asteracea::lignin::ReorderableFragment {
 				dom_key: reorderable_storage.dom_key,
 				content: #content,
 			})
@@ -273,9 +273,9 @@ impl<C: Configuration> For<C> {
 				#iterable,
 				#selector,
 			);
-			let mut for_items = ::#asteracea::bumpalo::vec![in #bump];
+			let mut for_items = #asteracea::bumpalo::vec![in #bump];
 			for item in sequence #content
-			::#asteracea::lignin::Node::Keyed(for_items.into_bump_slice())
+			#asteracea::lignin::Node::Keyed(for_items.into_bump_slice())
 		})
 		.pipe(Ok)
 	}
@@ -1,7 +1,5 @@
-use crate::{
-	asteracea_ident,
-	storage_context::{ParseContext, ParseWithContext},
-};
+use super::dom_binding::DomBindingDefinition;
+use crate::{asteracea_path, storage_context::ParseContext, workaround_module::Configuration};
 use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use syn::{parse::ParseStream, LitStr};
@@ -10,35 +8,40 @@ use unquote::unquote;
 pub struct HtmlComment {
 	open_span: Span,
 	text: LitStr,
+	dom_binding: Option<DomBindingDefinition>,
 }
 
-impl ParseWithContext for HtmlComment {
-	type Output = Self;
-
-	fn parse_with_context(
+impl HtmlComment {
+	pub fn parse_with_context<C: Configuration>(
 		input: ParseStream<'_>,
-		_cx: &mut ParseContext,
-	) -> syn::Result<Self::Output> {
+		cx: &mut ParseContext,
+	) -> syn::Result<Self> {
 		let open_span;
 		let text;
-		unquote!(input, #'open_span <!-- #text -->);
-		Ok(Self { open_span, text })
+		unquote!(input, #'open_span <!-- #text);
+		let dom_binding = DomBindingDefinition::parse_with_context::<C>(input, cx, "Comment")?;
+		unquote!(input, -->);
+		Ok(Self {
+			open_span,
+			text,
+			dom_binding,
+		})
 	}
-}
 
-impl HtmlComment {
 	pub fn part_tokens(&self) -> TokenStream {
 		let &Self {
 			open_span,
 			ref text,
+			ref dom_binding,
 		} = self;
 
-		let asteracea = asteracea_ident(open_span);
+		let asteracea = asteracea_path(open_span);
+		let dom_binding = DomBindingDefinition::binding_tokens(dom_binding.as_ref(), open_span);
 
 		quote_spanned! {open_span=>
 			#asteracea::lignin::Node::Comment {
 				comment: #text,
-				dom_binding: None, //TODO: Add DOM binding support.
+				dom_binding: #dom_binding,
 			}
 		}
 	}
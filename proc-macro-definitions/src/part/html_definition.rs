@@ -1,13 +1,13 @@
-use super::{GenerateContext, Part, PartKind};
+use super::{dom_binding::DomBindingDefinition, ElementNamespace, GenerateContext, Part, PartKind};
 use crate::{
-	asteracea_ident,
+	asteracea_path,
 	storage_context::{ParseContext, ParseWithContext},
 	util::Braced,
 	Configuration,
 };
-use either::Either;
 use proc_macro2::{Span, TokenStream};
-use quote::{quote_spanned, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
+use std::mem::replace;
 use syn::{
 	parse::{Parse, ParseStream, Result},
 	parse_quote_spanned,
@@ -22,6 +22,8 @@ mod kw {
 	custom_keyword!(with);
 	custom_keyword!(scope);
 	custom_keyword!(attribute);
+	custom_keyword!(is);
+	custom_keyword!(attrs);
 }
 
 enum AttributeDefinition {
@@ -32,7 +34,50 @@ enum AttributeDefinition {
 		Token![=],
 		AttributeValue,
 	),
+	/// `.key+ = value ⟦if {condition}⟧`, merged with the other entries for the same key, space-separated.
+	ListItem(
+		Token![.],
+		AttributeKey,
+		Token![+],
+		Token![=],
+		AttributeValue,
+		Option<(Token![if], Braced)>,
+	),
+	/// `.style.property⟦?⟧ = value`, merged into a single `style` attribute.
+	StyleProperty(
+		Token![.],
+		AttributeKey,
+		Token![.],
+		LitStr,
+		Option<Token![?]>,
+		Token![=],
+		AttributeValue,
+	),
 	RustBlock(Token![.], Braced),
+	/// `.:property = value`
+	Property(Token![.], Token![:], Ident, Token![=], AttributeValue),
+	/// `..attrs = {IntoIterator<Item = (&'bump str, &'bump str)>}`
+	Spread(Token![..], kw::attrs, Token![=], Braced),
+}
+
+/// Distinguishes `..attrs = …` from anonymous content (`..`).
+fn peek_attribute_spread(input: ParseStream) -> bool {
+	let fork = input.fork();
+	fork.parse::<Token![..]>().is_ok() && fork.peek(kw::attrs) && fork.peek2(Token![=])
+}
+
+impl AttributeDefinition {
+	/// The key of entries that are merged into one attribute value at render time.
+	fn merged_key(&self) -> Option<&AttributeKey> {
+		match self {
+			AttributeDefinition::ListItem(_, key, ..)
+			| AttributeDefinition::StyleProperty(_, key, ..) => Some(key),
+			AttributeDefinition::Assignment(..)
+			| AttributeDefinition::RustBlock(..)
+			| AttributeDefinition::Property(..)
+			| AttributeDefinition::Spread(..) => None,
+		}
+	}
 }
 
 enum AttributeKey {
@@ -40,17 +85,31 @@ enum AttributeKey {
 	Literal(LitStr),
 }
 
-impl ToTokens for AttributeKey {
-	fn to_tokens(&self, tokens: &mut TokenStream) {
+impl AttributeKey {
+	fn key_tokens(&self, namespace: ElementNamespace) -> TokenStream {
 		match self {
 			AttributeKey::Known(name) => {
-				let asteracea = asteracea_ident(name.span());
-				(quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
-					<dyn ::#asteracea::__::lignin_schema::html::attributes::#name>::NAME
-				})
-				.to_tokens(tokens)
+				let asteracea = asteracea_path(name.span());
+				let module = namespace.schema_module(name.span());
+				quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
+					<dyn #asteracea::__::lignin_schema::#module::attributes::#name>::NAME
+				}
 			}
-			AttributeKey::Literal(name) => name.to_tokens(tokens),
+			AttributeKey::Literal(name) => name.to_token_stream(),
+		}
+	}
+
+	fn name(&self) -> String {
+		match self {
+			AttributeKey::Known(name) => name.to_string(),
+			AttributeKey::Literal(name) => name.value(),
+		}
+	}
+
+	fn span(&self) -> Span {
+		match self {
+			AttributeKey::Known(name) => name.span(),
+			AttributeKey::Literal(name) => name.span(),
 		}
 	}
 }
@@ -99,15 +158,61 @@ impl Parse for AttributeValue {
 	}
 }
 
-//TODO: Add a Dynamic(Braced) variant.
 enum ElementName {
 	Custom(LitStr),
 	Known(Ident, Option<Ident>),
+	/// Validated at render time.
+	Dynamic(Braced),
+}
+
+/// Defines [`is_invalid_element_name_char`] along with [`invalid_element_name_char_tokens`] from the same source,
+/// so that literal element names are checked with the same rules as dynamic ones.
+macro_rules! invalid_element_name_char {
+	(|$c:ident| $($body:tt)*) => {
+		fn is_invalid_element_name_char($c: char) -> bool {
+			$($body)*
+		}
+
+		/// A closure equivalent to [`is_invalid_element_name_char`], used by `::asteracea::__::validate_element_name`.
+		pub fn invalid_element_name_char_tokens() -> TokenStream {
+			quote!(|$c: char| -> bool { $($body)* })
+		}
+	};
+}
+invalid_element_name_char! {
+	|c| c.is_whitespace() || c.is_control() || matches!(c, '/' | '<' | '>' | '=' | '"' | '\'')
+}
+
+/// `is = …`, for customized built-in elements.
+struct Is {
+	is: kw::is,
+	eq: Token![=],
+	value: AttributeValue,
+}
+
+impl Parse for Is {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let is = input.parse()?;
+		let eq = input.parse()?;
+		let value = input.parse()?;
+		if let AttributeValue::Literal(name) = &value {
+			if !name.value().contains('-') {
+				return Err(Error::new_spanned(
+					name,
+					"Customized built-in element names must contain a hyphen (`-`)",
+				));
+			}
+		}
+		Ok(Self { is, eq, value })
+	}
 }
 
 pub(crate) struct HtmlDefinition<C: Configuration> {
 	lt: Token![<],
 	name: ElementName,
+	namespace: ElementNamespace,
+	is: Option<Is>,
+	dom_binding: Option<DomBindingDefinition>,
 	attributes: Vec<AttributeDefinition>,
 	pub parts: Vec<Part<C>>,
 }
@@ -116,33 +221,138 @@ impl<C: Configuration> ParseWithContext for HtmlDefinition<C> {
 	type Output = Self;
 	fn parse_with_context(input: ParseStream<'_>, cx: &mut ParseContext) -> Result<Self> {
 		let lt = input.parse::<Token![<]>()?;
-		let name = if let Some(name @ LitStr { .. }) = input.parse().unwrap() {
+		let mut name = if let Some(name @ LitStr { .. }) = input.parse().unwrap() {
 			if name.value().contains(' ') {
 				return Err(Error::new_spanned(
 					name,
 					"Element names must not contain spaces",
 				));
 			}
-			Either::Left(name) // custom
+			if name.value().is_empty() || name.value().contains(is_invalid_element_name_char) {
+				return Err(Error::new_spanned(
+					name,
+					"Element names must not be empty or contain control characters or any of `/<>=\"'`",
+				));
+			}
+			ElementName::Custom(name)
+		} else if input.peek(Brace) {
+			ElementName::Dynamic(input.parse()?)
 		} else if let Some(name) = input.parse().unwrap() {
-			Either::Right(name) // known
+			ElementName::Known(name, None)
 		} else {
 			return Err(Error::new(
 				input.cursor().span(),
-				"Expected identifier or string literal (element name)",
+				"Expected identifier, string literal or Rust block (element name)",
 			));
 		};
 
+		let (namespace, children_namespace) = match &name {
+			ElementName::Custom(name) => (
+				cx.element_namespace.of_element(&name.value()),
+				cx.element_namespace.of_children(&name.value()),
+			),
+			ElementName::Known(name, _) => (
+				cx.element_namespace.of_element(&name.to_string()),
+				cx.element_namespace.of_children(&name.to_string()),
+			),
+			ElementName::Dynamic(_) => (cx.element_namespace, cx.element_namespace),
+		};
+
+		let is = if input.peek(kw::is) && input.peek2(Token![=]) {
+			Some(input.parse()?)
+		} else {
+			None
+		};
+
+		let mut dom_binding =
+			DomBindingDefinition::parse_with_context::<C>(input, cx, namespace.web_type())?;
+
 		let attributes = {
 			let mut attributes = Vec::new();
-			while input.peek(Token![.]) && !input.peek(Token![..]) {
+			while (input.peek(Token![.]) && !input.peek(Token![..])) || peek_attribute_spread(input)
+			{
+				if peek_attribute_spread(input) {
+					attributes.push(AttributeDefinition::Spread(
+						input.parse()?,
+						input.parse()?,
+						input.parse()?,
+						input.parse()?,
+					));
+					continue;
+				}
 				let dot = input.parse::<Token![.]>().expect("unreachable");
-				attributes.push(if input.peek(Ident) || input.peek(LitStr) {
-					let key;
+				let attribute = if input.peek(Token![:]) && !input.peek(Token![::]) {
+					let colon = input.parse()?;
+					let property;
+					let eq;
+					let value;
+					unquote!(input, #property #eq #value);
+					AttributeDefinition::Property(dot, colon, property, eq, value)
+				} else if input.peek(Ident) || input.peek(LitStr) {
+					let key: AttributeKey = input.parse()?;
+					if input.peek(Token![.]) {
+						if key.name() != "style" {
+							return Err(Error::new(
+								key.span(),
+								"Only `.style` supports property shorthands (`.style.property = …`)",
+							));
+						}
+						let property_dot = input.parse()?;
+						let property = if let Some(property) =
+							input.parse::<Option<LitStr>>().unwrap()
+						{
+							property
+						} else {
+							// CSS properties are kebab-case, which Rust identifiers can't express.
+							let property: Ident = input.parse()?;
+							LitStr::new(&property.to_string().replace('_', "-"), property.span())
+						};
+						let question: Option<Token![?]>;
+						let eq;
+						let value;
+						unquote!(input, #question #eq #value);
+						if question.is_some() && matches!(value, AttributeValue::Literal(_)) {
+							return Err(Error::new(
+								value.span(),
+								format!(
+									"Expected Rust block value for optional style property, but found `{}`",
+									value.to_token_stream(),
+								),
+							));
+						}
+						attributes.push(AttributeDefinition::StyleProperty(
+							dot,
+							key,
+							property_dot,
+							property,
+							question,
+							eq,
+							value,
+						));
+						continue;
+					}
+					if input.peek(Token![+=]) || input.peek(Token![+]) {
+						let (plus, eq) =
+							if let Some(plus_eq) = input.parse::<Option<Token![+=]>>().unwrap() {
+								(Token![+](plus_eq.spans[0]), Token![=](plus_eq.spans[1]))
+							} else {
+								(input.parse()?, input.parse()?)
+							};
+						let value = input.parse()?;
+						let condition = if input.peek(Token![if]) {
+							Some((input.parse()?, input.parse()?))
+						} else {
+							None
+						};
+						attributes.push(AttributeDefinition::ListItem(
+							dot, key, plus, eq, value, condition,
+						));
+						continue;
+					}
 					let question: Option<Token![?]>;
 					let eq;
 					let value;
-					unquote!(input, #key #question #eq #value);
+					unquote!(input, #question #eq #value);
 					if question.is_some() && matches!(value, AttributeValue::Literal(_)) {
 						return Err(Error::new(
 							value.span(),
@@ -163,21 +373,70 @@ impl<C: Configuration> ParseWithContext for HtmlDefinition<C> {
 						input.span(),
 						"Expected Rust block (Attribute) or an identifier or string literal (HTML attribute name)",
 					));
-				});
+				};
+				attributes.push(attribute);
+			}
+			for attribute in attributes.iter() {
+				if let AttributeDefinition::Assignment(_, key, ..) = attribute {
+					if let Some(merged) = attributes
+						.iter()
+						.filter_map(AttributeDefinition::merged_key)
+						.find(|merged| merged.name() == key.name())
+					{
+						return Err(Error::new(
+							merged.span(),
+							format!(
+								"`.{}` is also assigned as a whole. Use only either shorthand entries or a single assignment",
+								key.name(),
+							),
+						));
+					}
+				}
 			}
 			attributes
 		};
 
+		// Properties are assigned through the element's DOM binding, which is added here if necessary.
+		if let Some(colon) = attributes.iter().find_map(|attribute| match attribute {
+			AttributeDefinition::Property(_, colon, ..) => Some(colon),
+			_ => None,
+		}) {
+			if namespace != ElementNamespace::Html {
+				return Err(Error::new(
+					colon.span,
+					"Property bindings are only available on HTML elements.",
+				));
+			}
+			if cx.component_name.is_none() {
+				return Err(Error::new(
+					colon.span,
+					"Property bindings are only available within full components.",
+				));
+			}
+			if cx.property_bindings.get().is_none() {
+				cx.property_bindings.set(Some(colon.span));
+			}
+			if dom_binding.is_none() {
+				dom_binding = Some(DomBindingDefinition::hidden::<C>(
+					cx,
+					colon.span,
+					namespace.web_type(),
+				)?);
+			}
+		}
+
 		let mut parts = Vec::new();
+		let outer_namespace = replace(&mut cx.element_namespace, children_namespace);
 		while !input.peek(Token![>]) && !input.peek(Token![/]) {
 			if let Some(part) = Part::parse_with_context(input, cx)? {
 				parts.push(part);
 			}
 		}
+		cx.element_namespace = outer_namespace;
 
-		let name = if input.parse::<Token![/]>().is_ok() {
-			match name {
-				Either::Left(name) => {
+		if let Some(slash) = input.parse::<Option<Token![/]>>().unwrap() {
+			match &mut name {
+				ElementName::Custom(name) => {
 					let close_name: LitStr = input.parse()?;
 					// Named close.
 					if close_name.value() != name.value() {
@@ -186,31 +445,34 @@ impl<C: Configuration> ParseWithContext for HtmlDefinition<C> {
 							format_args!("Expected {:?}", name.value()),
 						));
 					}
-					ElementName::Custom(name)
 				}
-				Either::Right(name) => {
-					let closing_name: Ident = input.parse()?;
+				ElementName::Known(name, closing_name) => {
+					let close_name: Ident = input.parse()?;
 					// Named close.
-					if closing_name != name {
+					if close_name != *name {
 						return Err(Error::new_spanned(
-							closing_name,
+							close_name,
 							format_args!("Expected `{}`", name),
 						));
 					}
-					ElementName::Known(name, Some(closing_name))
+					*closing_name = Some(close_name);
+				}
+				ElementName::Dynamic(_) => {
+					return Err(Error::new(
+						slash.span,
+						"Dynamic element names can't be restated when closing the element. Use `>` instead.",
+					))
 				}
 			}
-		} else {
-			match name {
-				Either::Left(name) => ElementName::Custom(name),
-				Either::Right(name) => ElementName::Known(name, None),
-			}
-		};
+		}
 		input.parse::<Token![>]>()?;
 
 		Ok(Self {
 			lt,
 			name,
+			namespace,
+			is,
+			dom_binding,
 			attributes,
 			parts,
 		})
@@ -222,26 +484,89 @@ impl<C: Configuration> HtmlDefinition<C> {
 		let Self {
 			lt,
 			name,
+			namespace,
+			is,
+			dom_binding,
 			attributes,
 			parts,
 		} = self;
 
-		let asteracea = asteracea_ident(lt.span());
+		let asteracea = asteracea_path(lt.span());
 		let thread_safety = &cx.thread_safety;
 
 		let bump = Ident::new("bump", lt.span().resolved_at(Span::call_site()));
 
+		let module = namespace.schema_module(lt.span());
+		let node_variant = namespace.node_variant(lt.span());
+		let properties = attributes
+			.iter()
+			.filter_map(|attribute| match attribute {
+				AttributeDefinition::Property(dot, _, property, eq, value) => {
+					let name = LitStr::new(property_name(property).as_str(), property.span());
+					let value = match value {
+						AttributeValue::Literal(l) => l.to_token_stream(),
+						AttributeValue::Blocked(b) => {
+							let stmts = &b.contents;
+							// Suppress unneeded-braces warning.
+							quote_spanned!(b.brace_token.span.join().resolved_at(Span::mixed_site())=> {#stmts})
+						}
+					};
+					Some(quote_spanned! {dot.span.resolved_at(Span::mixed_site())=>
+						(#name, {
+							let value #eq #value;
+							#asteracea::PropertyValue::into_property(value)
+						})
+					})
+				}
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		let assign_properties = if properties.is_empty() {
+			None
+		} else {
+			let access = dom_binding
+				.as_ref()
+				.expect("DOM binding for property bindings")
+				.access();
+			let self_ = quote_spanned!(lt.span()=> self);
+			Some(quote_spanned! {lt.span().resolved_at(Span::mixed_site())=>
+				let properties = [#(#properties,)*];
+				#access.__Asteracea__assign_properties(
+					#self_.__Asteracea__dom_properties.as_ref(),
+					&properties,
+				);
+			})
+		};
+		let mut property_index = 0;
+		let dom_binding = DomBindingDefinition::binding_tokens(dom_binding.as_ref(), lt.span());
+		let creation_options = match is {
+			None => quote_spanned! {lt.span.resolved_at(Span::mixed_site())=>
+				#asteracea::lignin::ElementCreationOptions::new()
+			},
+			Some(Is { is, eq, value }) => quote_spanned! {is.span.resolved_at(Span::mixed_site())=>
+				{
+					let is: &'bump str #eq #value;
+					#asteracea::lignin::ElementCreationOptions::new().with_is(::core::option::Option::Some(is))
+				}
+			},
+		};
+
 		let has_optional_attributes = attributes.iter().any(|a| match a {
 			AttributeDefinition::Assignment(_, _, Some(Question { .. }), _, _) => true,
 			AttributeDefinition::Assignment(_, _, None, _, _) => false,
+			// Omitted when empty.
+			AttributeDefinition::ListItem(..) | AttributeDefinition::StyleProperty(..) => true,
 			AttributeDefinition::RustBlock(_, _) => false,
+			AttributeDefinition::Property(..) | AttributeDefinition::Spread(..) => true,
 		});
 		let validate_attributes = match name {
-			ElementName::Custom(_) => vec![],
+			ElementName::Custom(_) | ElementName::Dynamic(_) => vec![],
 			ElementName::Known(tag_name, _) => attributes
 				.iter()
 				.filter_map(|attribute| match attribute {
-					AttributeDefinition::Assignment(_, AttributeKey::Known(name), _, _, _) => {
+					AttributeDefinition::Assignment(_, AttributeKey::Known(name), _, _, _)
+					| AttributeDefinition::ListItem(_, AttributeKey::Known(name), ..)
+					| AttributeDefinition::StyleProperty(_, AttributeKey::Known(name), ..) => {
 						// Move validation errors onto the attribute name.
 						let tag_name = Ident::new(&tag_name.to_string(), name.span());
 						Some(
@@ -249,8 +574,8 @@ impl<C: Configuration> HtmlDefinition<C> {
 								// Already flagged where the attribute name is resolved.
 								// Ignored here so a deprecated element isn't warned about on the attribute.
 								#[allow(deprecated)]
-								::#asteracea::__::lignin_schema::html::attributes::#name::<_>::static_validate_on(
-									::#asteracea::__::lignin_schema::html::elements::#tag_name
+								#asteracea::__::lignin_schema::#module::attributes::#name::<_>::static_validate_on(
+									#asteracea::__::lignin_schema::#module::elements::#tag_name
 								);
 							},
 						)
@@ -259,10 +584,21 @@ impl<C: Configuration> HtmlDefinition<C> {
 				})
 				.collect::<Vec<_>>(),
 		};
+		let mut merged_names = vec![];
 		let attributes = attributes
 			.iter()
+			.filter(|a| match a.merged_key() {
+				// Merged entries are all generated at the first one.
+				Some(key) if merged_names.contains(&key.name()) => false,
+				Some(key) => {
+					merged_names.push(key.name());
+					true
+				}
+				None => true,
+			})
 			.map(|a| match a {
 				AttributeDefinition::Assignment(dot, key, question, eq, value) => {
+					let key = key.key_tokens(*namespace);
 					let span = dot.span.resolved_at(Span::mixed_site());
 					match (has_optional_attributes, question) {
 						(false, Some(_)) => unreachable!(),
@@ -300,6 +636,58 @@ impl<C: Configuration> HtmlDefinition<C> {
 						},
 					}
 				}
+				AttributeDefinition::ListItem(dot, key, ..)
+				| AttributeDefinition::StyleProperty(dot, key, ..) => {
+					let span = dot.span.resolved_at(Span::mixed_site());
+					let name = key.name();
+					let entries = attributes
+						.iter()
+						.filter(|a| matches!(a.merged_key(), Some(key) if key.name() == name))
+						.map(|a| merged_entry_tokens(a, &asteracea))
+						.collect::<Vec<_>>();
+					let key = key.key_tokens(*namespace);
+					quote_spanned! {span=> {
+						let name = #key; // Always evaluate this.
+						let mut value = #asteracea::bumpalo::collections::String::new_in(#bump);
+						#(#entries)*
+						if !value.is_empty() {
+							attrs.push(#asteracea::lignin::Attribute {
+								name,
+								value: value.into_bump_str(),
+							})
+						}
+					}}
+				}
+				AttributeDefinition::Property(dot, colon, property, ..) => {
+					let span = dot
+						.span
+						.join(colon.span)
+						.unwrap_or(dot.span)
+						.resolved_at(Span::mixed_site());
+					let index = property_index;
+					property_index += 1;
+					// Evaluated along with the other properties, in `assign_properties`.
+					match property_attribute_name(&property_name(property)) {
+						Some(name) => quote_spanned! {span=>
+							if let ::core::option::Option::Some(value) = properties[#index].1.into_attribute_value() {
+								attrs.push(#asteracea::lignin::Attribute {
+									name: #name,
+									value,
+								})
+							}
+						},
+						None => TokenStream::new(),
+					}
+				}
+				AttributeDefinition::Spread(dot2, _, eq, block) => {
+					let stmts = &block.contents;
+					quote_spanned! {dot2.spans[0].resolved_at(Span::mixed_site())=> {
+						let spread #eq { #stmts };
+						for (name, value) in spread {
+							attrs.push(#asteracea::lignin::Attribute { name, value });
+						}
+					}}
+				}
 				AttributeDefinition::RustBlock(dot, block) => {
 					let span = dot.span.resolved_at(Span::mixed_site());
 					if has_optional_attributes {
@@ -340,8 +728,8 @@ impl<C: Configuration> HtmlDefinition<C> {
 				});
 			}
 			quote_spanned! {child_stream.span()=>
-				::#asteracea::lignin::Node::Multi::<'bump, #thread_safety>(&*#bump.alloc_try_with(
-					|| -> ::std::result::Result<_, ::#asteracea::error::Escalation> {
+				#asteracea::lignin::Node::Multi::<'bump, #thread_safety>(&*#bump.alloc_try_with(
+					|| -> ::std::result::Result<_, #asteracea::error::Escalation> {
 						::std::result::Result::Ok([#child_stream])
 					}
 				)?)
@@ -351,6 +739,23 @@ impl<C: Configuration> HtmlDefinition<C> {
 		let (event_bindings, parts): (Vec<&Part<C>>, Vec<_>) = parts
 			.iter()
 			.partition(|part| part.kind() == PartKind::EventBinding);
+		let validate_events = match name {
+			ElementName::Custom(_) | ElementName::Dynamic(_) => vec![],
+			ElementName::Known(tag_name, _) => event_bindings
+				.iter()
+				.filter_map(|part| part.as_event_binding()?.known_name())
+				.map(|name| {
+					// Move validation errors onto the event name.
+					let tag_name = Ident::new(&tag_name.to_string(), name.span());
+					quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
+						#[allow(deprecated)]
+						#asteracea::__::lignin_schema::events::#name::<_>::static_validate_on(
+							#asteracea::__::lignin_schema::#module::elements::#tag_name
+						);
+					}
+				})
+				.collect::<Vec<_>>(),
+		};
 		let mut event_stream = TokenStream::new();
 		for event_binding in event_bindings.into_iter() {
 			let event_binding = event_binding.part_tokens(cx)?;
@@ -363,23 +768,37 @@ impl<C: Configuration> HtmlDefinition<C> {
 		};
 
 		assert_eq!(parts.len(), 0);
-		Ok(match name {
+		let element = match name {
 			ElementName::Custom(name) => {
 				quote_spanned! {lt.span.resolved_at(Span::mixed_site())=> {
 					let children = #children;
-					//TODO: Add MathML and SVG support.
-					::#asteracea::lignin::Node::HtmlElement::<'bump, #thread_safety> {
+					#asteracea::lignin::Node::#node_variant::<'bump, #thread_safety> {
 						element: #bump.alloc_with(||
 								#asteracea::lignin::Element {
 									name: #name,
-									creation_options: ::#asteracea::lignin::ElementCreationOptions::new(), //TODO: Add `is` support.
+									creation_options: #creation_options,
 									attributes: #attributes,
 									content: children,
 									event_bindings: #event_bindings,
 								}
 							),
-						//TODO: Add DOM binding support.
-						dom_binding: None,
+						dom_binding: #dom_binding,
+					}
+				}}
+			}
+			ElementName::Dynamic(name) => {
+				quote_spanned! {lt.span.resolved_at(Span::mixed_site())=> {
+					#asteracea::lignin::Node::#node_variant::<'bump, #thread_safety> {
+						element: #bump.alloc_try_with(|| -> ::core::result::Result::<_, #asteracea::error::Escalation> {
+							::core::result::Result::Ok(#asteracea::lignin::Element {
+								name: #asteracea::__::validate_element_name(#name)?,
+								creation_options: #creation_options,
+								attributes: #attributes,
+								event_bindings: #event_bindings,
+								content: #children,
+							})
+						})?,
+						dom_binding: #dom_binding,
 					}
 				}}
 			}
@@ -387,7 +806,7 @@ impl<C: Configuration> HtmlDefinition<C> {
 				let validate_has_content = if has_content {
 					Some(
 						quote_spanned! {name.span().resolved_at(Span::mixed_site())=>
-							::#asteracea::__::lignin_schema::HasContent::static_validate_on(::#asteracea::__::lignin_schema::html::elements::#name);
+							#asteracea::__::lignin_schema::HasContent::static_validate_on(#asteracea::__::lignin_schema::#module::elements::#name);
 						},
 					)
 				} else {
@@ -395,32 +814,119 @@ impl<C: Configuration> HtmlDefinition<C> {
 				};
 				let document_closing = closing_name.as_ref().map(|closing_name| {
 					quote_spanned! {closing_name.span().resolved_at(Span::mixed_site())=>
-						let _ = ::#asteracea::__::lignin_schema::html::elements::#closing_name;
+						let _ = #asteracea::__::lignin_schema::#module::elements::#closing_name;
 					}
 				});
 				quote_spanned! {lt.span.resolved_at(Span::mixed_site())=> {
-					//TODO: Add MathML and SVG support.
-					::#asteracea::lignin::Node::HtmlElement::<'bump, #thread_safety> {
-						element: #bump.alloc_try_with(|| -> ::core::result::Result::<_, ::#asteracea::error::Escalation> {
+					#asteracea::lignin::Node::#node_variant::<'bump, #thread_safety> {
+						element: #bump.alloc_try_with(|| -> ::core::result::Result::<_, #asteracea::error::Escalation> {
 							#validate_has_content
 							#(#validate_attributes)*
+							#(#validate_events)*
 							#document_closing
-							//TODO: Validate attributes.
-							//TODO: Validate events.
 
-							::core::result::Result::Ok(::#asteracea::lignin::Element {
-								name: ::#asteracea::__::lignin_schema::html::elements::#name::TAG_NAME,
-								creation_options: ::#asteracea::lignin::ElementCreationOptions::new(), //TODO: Add `is` support.
+							::core::result::Result::Ok(#asteracea::lignin::Element {
+								name: #asteracea::__::lignin_schema::#module::elements::#name::TAG_NAME,
+								creation_options: #creation_options,
 								attributes: #attributes,
 								event_bindings: #event_bindings,
 								content: #children,
 							})
 						})?,
-						//TODO: Add DOM binding support.
-						dom_binding: None,
+						dom_binding: #dom_binding,
 					}
 				}}
 			}
+		};
+		Ok(match assign_properties {
+			None => element,
+			Some(assign_properties) => quote_spanned! {lt.span.resolved_at(Span::mixed_site())=> {
+				#assign_properties
+				#element
+			}},
 		})
 	}
 }
+
+/// Appends one `.key+ = …` or `.style.property = …` entry to `value` (a `bumpalo::collections::String`).
+fn merged_entry_tokens(attribute: &AttributeDefinition, asteracea: &TokenStream) -> TokenStream {
+	match attribute {
+		AttributeDefinition::ListItem(dot, _, _, eq, value, condition) => {
+			let span = dot.span.resolved_at(Span::mixed_site());
+			let push = quote_spanned! {span=> {
+				let item: &str #eq #value;
+				if !value.is_empty() {
+					value.push(' ');
+				}
+				value.push_str(item);
+			}};
+			match condition {
+				None => push,
+				Some((if_, condition)) => {
+					let stmts = &condition.contents;
+					quote_spanned! {if_.span.resolved_at(Span::mixed_site())=>
+						if { #stmts } #push
+					}
+				}
+			}
+		}
+		AttributeDefinition::StyleProperty(dot, _, _, property, question, eq, value) => {
+			let span = dot.span.resolved_at(Span::mixed_site());
+			let push = quote_spanned! {span=> {
+				if !value.is_empty() {
+					value.push_str("; ");
+				}
+				value.push_str(#property);
+				value.push_str(": ");
+				value.push_str(property_value);
+			}};
+			match (question, value) {
+				(Some(question), AttributeValue::Blocked(b)) => {
+					let stmts = &b.contents;
+					quote_spanned! {question.span.resolved_at(Span::mixed_site())=>
+						if let ::core::option::Option::Some(property_value) #eq #asteracea::ConditionalAttributeValue::into_str_option({ #stmts }) #push
+					}
+				}
+				(Some(_), AttributeValue::Literal(_)) => unreachable!(),
+				(None, value) => quote_spanned! {span=> {
+					let property_value: &str #eq #value;
+					#push
+				}},
+			}
+		}
+		AttributeDefinition::Assignment(..)
+		| AttributeDefinition::RustBlock(..)
+		| AttributeDefinition::Property(..)
+		| AttributeDefinition::Spread(..) => unreachable!(),
+	}
+}
+
+/// The JavaScript name of a DOM property (`.:property = …`), as written.
+fn property_name(property: &Ident) -> String {
+	property.to_string().trim_start_matches("r#").to_string()
+}
+
+/// The attribute a DOM property (`.:property = …`) is rendered as, iff the attribute reflects the property's (initial) value.
+fn property_attribute_name(property: &str) -> Option<&'static str> {
+	Some(match property {
+		"accessKey" => "accesskey",
+		"checked" | "defaultChecked" => "checked",
+		"className" => "class",
+		"dir" => "dir",
+		"disabled" => "disabled",
+		"hidden" => "hidden",
+		"htmlFor" => "for",
+		"id" => "id",
+		"lang" => "lang",
+		"multiple" => "multiple",
+		"name" => "name",
+		"placeholder" => "placeholder",
+		"readOnly" => "readonly",
+		"required" => "required",
+		"selected" | "defaultSelected" => "selected",
+		"tabIndex" => "tabindex",
+		"title" => "title",
+		"value" | "defaultValue" => "value",
+		_ => return None,
+	})
+}
@@ -88,7 +88,7 @@ impl<C> ParseWithContext for LetSelf<C> {
 	}
 }
 
-fn guesstimate_type(value: &Expr) -> Result<Type> {
+pub(super) fn guesstimate_type(value: &Expr) -> Result<Type> {
 	let mut expr = value;
 	let type_: Type = loop {
 		expr = match expr {
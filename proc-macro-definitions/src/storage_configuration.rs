@@ -3,19 +3,20 @@ use quote::{quote_spanned, ToTokens};
 use std::iter;
 use syn::{
 	parse::{Parse, ParseStream},
-	parse2, parse_quote_spanned,
+	parse_quote_spanned,
 	punctuated::{Pair, Punctuated},
 	spanned::Spanned,
 	token::Brace,
+	visit_mut::VisitMut,
 	AngleBracketedGenericArguments, Attribute, Error, ExprPath, Fields, FieldsNamed,
 	GenericArgument, GenericParam, Generics, Ident, ImplItem, ImplItemFn, Item, ItemImpl,
-	ItemStruct, LifetimeParam, Path, PathArguments, PathSegment, Result, Token, TypeParam,
-	TypePath, Visibility, WhereClause,
+	ItemStruct, Lifetime, LifetimeParam, Path, PathArguments, PathSegment, Result, Token,
+	TypeParam, TypePath, Visibility, WhereClause,
 };
 use tap::Pipe as _;
 use unquote::unquote;
 
-use crate::{asteracea_ident, storage_context::StorageContext};
+use crate::{asteracea_path, storage_context::StorageContext};
 
 /// ⟦⦃priv‖⦅Visibility⦆⦄ …⦅StorageTypeConfiguration⦆⟧
 #[allow(clippy::large_enum_variant)]
@@ -122,46 +123,158 @@ impl Parse for StorageTypeConfiguration {
 	}
 }
 
+/// Derives the nested storage generics from a predefined storage type's `arguments`, in argument order.
+///
+/// Bare identifiers and lifetimes become parameters, as declared by `parent_generics` if possible.
+/// Other arguments (including associated type bindings like `Item = U`)
+/// contribute the `parent_generics` parameters they reference instead, so concrete ones like `Vec<u8>` or `3` don't need any.
 fn generic_arguments_to_generic_params(
 	arguments: &Punctuated<GenericArgument, Token![,]>,
+	parent_generics: &Generics,
 ) -> Result<Punctuated<GenericParam, Token![,]>> {
-	arguments
-		.pairs()
-		.map(|pair| {
-			Ok(Pair::new(
-				match pair.value() {
-					GenericArgument::Lifetime(l) => GenericParam::Lifetime(LifetimeParam {
-						attrs: vec![],
-						lifetime: l.clone(),
-						colon_token: None,
-						bounds: Punctuated::default(),
+	/// Collects the `parent_generics` parameters referenced by the visited syntax, in order of appearance.
+	struct References<'a> {
+		parent_generics: &'a Generics,
+		params: Vec<GenericParam>,
+	}
+	impl VisitMut for References<'_> {
+		fn visit_ident_mut(&mut self, i: &mut Ident) {
+			self.params
+				.extend(parent_param(self.parent_generics, &i.to_string()))
+		}
+
+		fn visit_lifetime_mut(&mut self, i: &mut Lifetime) {
+			// Not recursing, so that `'a` doesn't reference a type parameter `a`.
+			self.params
+				.extend(parent_param(self.parent_generics, &i.to_string()))
+		}
+	}
+
+	let mut params = Punctuated::<GenericParam, Token![,]>::new();
+	for argument in arguments {
+		let argument = match argument {
+			GenericArgument::Constraint(constraint) => {
+				return Err(Error::new_spanned(
+					constraint,
+					"Associated type bounds are not supported here. Use a `where` clause instead.",
+				))
+			}
+			GenericArgument::AssocType(binding) => GenericArgument::Type(binding.ty.clone()),
+			GenericArgument::AssocConst(binding) => GenericArgument::Const(binding.value.clone()),
+			other => other.clone(),
+		};
+		let referenced = match argument {
+			GenericArgument::Lifetime(lifetime) => {
+				vec![
+					parent_param(parent_generics, &lifetime.to_string()).unwrap_or_else(|| {
+						GenericParam::Lifetime(LifetimeParam {
+							attrs: vec![],
+							lifetime,
+							colon_token: None,
+							bounds: Punctuated::default(),
+						})
 					}),
-					GenericArgument::Type(t) => GenericParam::Type(TypeParam {
-						attrs: vec![],
-						ident: parse2(t.to_token_stream())?,
-						colon_token: None,
-						bounds: Punctuated::default(),
-						eq_token: None,
-						default: None,
+				]
+			}
+			GenericArgument::Type(Type::Path(TypePath { qself: None, path }))
+				if path.get_ident().is_some() =>
+			{
+				let ident = path.get_ident().unwrap();
+				vec![
+					parent_param(parent_generics, &ident.to_string()).unwrap_or_else(|| {
+						GenericParam::Type(TypeParam {
+							attrs: vec![],
+							ident: ident.clone(),
+							colon_token: None,
+							bounds: Punctuated::default(),
+							eq_token: None,
+							default: None,
+						})
 					}),
-					GenericArgument::AssocConst(_) => {
-						todo!("storage configuration generic associated const")
-					}
-					GenericArgument::AssocType(_) => {
-						todo!("storage configuration generic associated type")
-					}
-					GenericArgument::Constraint(_) => {
-						todo!("storage configuration generic constraint")
-					}
-					GenericArgument::Const(_) => {
-						todo!("storage configuration generic const")
-					}
-					_ => unimplemented!("storage configuration generic unaccounted"),
-				},
-				pair.punct().cloned().cloned(),
-			))
-		})
-		.collect()
+				]
+			}
+			mut complex => {
+				let mut references = References {
+					parent_generics,
+					params: vec![],
+				};
+				references.visit_generic_argument_mut(&mut complex);
+				references.params
+			}
+		};
+		for param in referenced {
+			// Repeated arguments must not declare a parameter twice.
+			if !params
+				.iter()
+				.any(|existing| param_name(existing) == param_name(&param))
+			{
+				params.push(param)
+			}
+		}
+	}
+	Ok(params)
+}
+
+/// Formats the name of a generic parameter, including the `'` of lifetimes.
+fn param_name(param: &GenericParam) -> String {
+	match param {
+		GenericParam::Lifetime(LifetimeParam { lifetime, .. }) => lifetime.to_string(),
+		GenericParam::Type(TypeParam { ident, .. })
+		| GenericParam::Const(ConstParam { ident, .. }) => ident.to_string(),
+	}
+}
+
+/// Finds the parameter `name` (as formatted by [`param_name`]) in `generics`.
+fn parent_param(generics: &Generics, name: &str) -> Option<GenericParam> {
+	generics
+		.params
+		.iter()
+		.find(|param| param_name(param) == name)
+		.map(without_default)
+}
+
+/// Defaults aren't allowed on the generated storage types' `impl` blocks.
+fn without_default(param: &GenericParam) -> GenericParam {
+	match param.clone() {
+		GenericParam::Lifetime(lifetime_param) => GenericParam::Lifetime(LifetimeParam {
+			attrs: vec![],
+			..lifetime_param
+		}),
+		GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+			attrs: vec![],
+			eq_token: None,
+			default: None,
+			..type_param
+		}),
+		GenericParam::Const(const_param) => GenericParam::Const(ConstParam {
+			attrs: vec![],
+			eq_token: None,
+			default: None,
+			..const_param
+		}),
+	}
+}
+
+/// Rejects bare type parameters that are instantiated with one of the `parent_generics`' const parameters,
+/// as `struct Buf::<N>` must be written as `struct Buf::<const N: usize>` to accept an outer `const N: usize`.
+fn check_const_params(generics: &Generics, parent_generics: &Generics) -> Result<()> {
+	for param in &generics.params {
+		if let GenericParam::Type(TypeParam { ident, .. }) = param {
+			if let Some(GenericParam::Const(ConstParam { ty, .. })) =
+				parent_param(parent_generics, &ident.to_string())
+			{
+				return Err(Error::new_spanned(
+					param,
+					format!(
+						"`{0}` is a const parameter of the surrounding storage. Declare it as `const {0}: {1}` here.",
+						ident,
+						ty.to_token_stream(),
+					),
+				));
+			}
+		}
+	}
+	Ok(())
 }
 
 use syn::{ConstParam, Type};
@@ -293,13 +406,16 @@ impl StorageTypeConfiguration {
 		.pipe(Ok)
 	}
 
-	pub fn generics(&self) -> Result<Option<Generics>> {
+	pub fn generics(&self, parent_generics: &Generics) -> Result<Option<Generics>> {
 		match self {
 			StorageTypeConfiguration::Anonymous => None,
 			StorageTypeConfiguration::Generated {
 				generics: (_, generics),
 				..
-			} => Some(generics.clone()),
+			} => {
+				check_const_params(generics, parent_generics)?;
+				Some(generics.clone())
+			}
 			StorageTypeConfiguration::Predefined {
 				type_path,
 				where_clause,
@@ -309,7 +425,10 @@ impl StorageTypeConfiguration {
 					PathArguments::None => Generics::default(),
 					PathArguments::AngleBracketed(a_bra_args) => Generics {
 						lt_token: Some(a_bra_args.lt_token),
-						params: generic_arguments_to_generic_params(&a_bra_args.args)?,
+						params: generic_arguments_to_generic_params(
+							&a_bra_args.args,
+							parent_generics,
+						)?,
 						gt_token: Some(a_bra_args.gt_token),
 						where_clause: None,
 					},
@@ -360,7 +479,9 @@ impl StorageTypeConfiguration {
 		parent_generics: &Generics,
 	) -> Result<Vec<Item>> {
 		let span = ident.span();
-		let generics = self.generics()?.unwrap_or_else(|| parent_generics.clone());
+		let generics = self
+			.generics(parent_generics)?
+			.unwrap_or_else(|| parent_generics.clone());
 
 		let fields = contents.fields(self, &generics);
 
@@ -471,9 +592,9 @@ impl StorageTypeConfiguration {
 					::std::compile_error!("Asteracea can't soundly generate named generic storage context types if anything inside requires pinning :(\n(Once min_specialization lands, the required static assert against `Self: Unpin` will become available. For now, please use a `box <…>`-expression with either anonymous or manually defined storage context type to pin any child components in a heap allocation.)\n\nSee also: https://github.com/Tamschi/Asteracea/pull/31");
 				}
 			} else {
-				let asteracea = asteracea_ident(span);
+				let asteracea = asteracea_path(span);
 				quote_spanned! {span=>
-					::#asteracea::__::static_assertions::assert_not_impl_any!(#ident: ::std::marker::Unpin);
+					#asteracea::__::static_assertions::assert_not_impl_any!(#ident: ::std::marker::Unpin);
 				}
 			}))
 			}
@@ -1,7 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+};
 
 use crate::{
-	component_declaration::FieldDefinition, storage_configuration::StorageTypeConfiguration,
+	component_declaration::FieldDefinition, part::ElementNamespace,
+	storage_configuration::StorageTypeConfiguration,
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
@@ -19,7 +23,19 @@ pub struct ParseContext<'a> {
 	pub storage_generics: &'a Generics,
 	pub storage_context: StorageContext,
 	pub assorted_items: Vec<Item>,
-	pub callback_registrations: Rc<RefCell<Vec<(Ident, Type)>>>,
+	pub callback_registrations: Rc<RefCell<Vec<(Ident, Type, Option<Type>)>>>,
+	/// The location of the first `async fn` event handler, which requires an `EventTasks` field on the component.
+	pub async_event_handlers: Rc<Cell<Option<Span>>>,
+	/// The location of the first property binding, which requires a `DomProperties` field on the component.
+	pub property_bindings: Rc<Cell<Option<Span>>>,
+	/// The location of the first event modifier or key filter, which requires a `DomEvents` field on the component.
+	pub dom_events: Rc<Cell<Option<Span>>>,
+	/// The namespace of elements parsed in this context.
+	pub element_namespace: ElementNamespace,
+	/// The location of the content child being parsed, if any.
+	///
+	/// Content children may be rendered any number of times per render (via `...`), so they can't contain DOM bindings.
+	pub content_child: Option<Span>,
 }
 
 impl<'a> ParseContext<'a> {
@@ -39,6 +55,11 @@ impl<'a> ParseContext<'a> {
 			},
 			assorted_items: vec![],
 			callback_registrations: Rc::default(),
+			async_event_handlers: Rc::default(),
+			property_bindings: Rc::default(),
+			dom_events: Rc::default(),
+			element_namespace: ElementNamespace::Html,
+			content_child: None,
 		}
 	}
 
@@ -54,6 +75,11 @@ impl<'a> ParseContext<'a> {
 			},
 			assorted_items: vec![],
 			callback_registrations: Rc::default(),
+			async_event_handlers: Rc::default(),
+			property_bindings: Rc::default(),
+			dom_events: Rc::default(),
+			element_namespace: ElementNamespace::Html,
+			content_child: None,
 		}
 	}
 
@@ -73,6 +99,11 @@ impl<'a> ParseContext<'a> {
 			},
 			assorted_items: vec![],
 			callback_registrations: Rc::clone(&self.callback_registrations),
+			async_event_handlers: Rc::clone(&self.async_event_handlers),
+			property_bindings: Rc::clone(&self.property_bindings),
+			dom_events: Rc::clone(&self.dom_events),
+			element_namespace: self.element_namespace,
+			content_child: self.content_child,
 		}
 	}
 }
@@ -213,8 +244,8 @@ fn strip_params(
 ) -> Punctuated<GenericParam, Token![,]> {
 	params
 		.pairs()
-		.map(|pair| {
-			Pair::new(
+		.filter_map(|pair| {
+			Some(Pair::new(
 				match pair.value() {
 					GenericParam::Type(t) => GenericParam::Type(TypeParam {
 						attrs: vec![],
@@ -230,17 +261,11 @@ fn strip_params(
 						colon_token: None,
 						bounds: Punctuated::default(),
 					}),
-					GenericParam::Const(c) => GenericParam::Type(TypeParam {
-						attrs: vec![],
-						ident: c.ident.clone(),
-						colon_token: None,
-						bounds: Punctuated::default(),
-						eq_token: None,
-						default: None,
-					}),
+					// Unused const parameters are fine, so these don't need to be phantom.
+					GenericParam::Const(_) => return None,
 				},
 				pair.punct().cloned().cloned(),
-			)
+			))
 		})
 		.collect()
 }
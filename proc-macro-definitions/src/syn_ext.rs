@@ -280,7 +280,15 @@ impl PatIdent {
 				attrs: self.attrs,
 				member: self.ident.to_member(),
 				colon_token: None,
-				pat: self.ident.to_pat().into(),
+				// Shorthand field patterns keep `ref` and `mut`.
+				pat: Pat::Ident(PatIdent {
+					attrs: vec![],
+					by_ref: self.by_ref,
+					mutability: self.mutability,
+					ident: self.ident,
+					subpat: None,
+				})
+				.into(),
 			},
 		})
 	}
@@ -1,11 +1,18 @@
+use crate::{
+	error::{Escalate, Escalation, InvalidElementName, TraceFrame},
+	services::{DomEvents, KeyboardState},
+};
 use core::fmt::Debug;
-use lignin::CallbackRegistration;
+use lignin::{web::Event, CallbackRegistration};
 use std::{
 	fmt::{self, Formatter},
 	mem::ManuallyDrop,
 	pin::Pin,
 	rc::Rc,
-	sync::Arc,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc, Mutex, PoisonError,
+	},
 };
 use try_lazy_init::Lazy;
 
@@ -43,6 +50,199 @@ impl<R: ?Sized, T, F> CallbackHandler<R, T, Pin<&'static R>> for F where F: FnOn
 pub type DroppableLazyCallbackRegistration<Component, ParameterFn> =
 	ManuallyDrop<Lazy<CallbackRegistration<Component, ParameterFn>>>;
 
+// See `DroppableLazyCallbackRegistration`.
+pub type DroppablePayloadCallbackRegistrations<Component, Payload> =
+	ManuallyDrop<PayloadCallbackRegistrations<Component, Payload>>;
+
+/// Backs an event binding with a per-render payload (`on … = with {…}: Type …`).
+///
+/// Each time the binding is rendered, it claims the next slot, so that e.g. each row of a `for` loop
+/// receives its own [`CallbackRegistration`] and payload. [`.rewind()`](`PayloadCallbackRegistrations::rewind`)
+/// is called at the start of each `.render(…)` call to start claiming slots from the front again.
+///
+/// Slots that are claimed again keep their registration (and [`CallbackRef`](`lignin::CallbackRef`)), so they stay stable between renders.
+/// Slots that weren't claimed during a render are dropped along with their payload at its end.
+pub struct PayloadCallbackRegistrations<Component, Payload> {
+	slots: Mutex<Vec<Pin<Box<PayloadSlot<Component, Payload>>>>>,
+	cursor: AtomicUsize,
+}
+
+impl<Component, Payload> Default for PayloadCallbackRegistrations<Component, Payload> {
+	fn default() -> Self {
+		Self {
+			slots: Mutex::default(),
+			cursor: AtomicUsize::new(0),
+		}
+	}
+}
+
+impl<Component, Payload: Clone> PayloadCallbackRegistrations<Component, Payload> {
+	/// Starts claiming slots from the front again.
+	///
+	/// Slots that weren't claimed again by the time the returned guard is dropped are released.
+	#[must_use = "Unclaimed slots are released when the guard is dropped."]
+	pub fn rewind(&self) -> RewoundPayloadCallbackRegistrations<'_, Component, Payload> {
+		self.cursor.store(0, Ordering::Relaxed);
+		RewoundPayloadCallbackRegistrations(self)
+	}
+
+	/// Claims the next slot, storing `payload` in it.
+	///
+	/// `handler` is only used when a new slot is created.
+	#[must_use]
+	pub fn claim<'a>(
+		&'a self,
+		component: Pin<&'a Component>,
+		handler: fn(Pin<&Component>, Event, Payload),
+		payload: Payload,
+	) -> &'a CallbackRegistration<PayloadSlot<Component, Payload>, fn(Event)> {
+		let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+		let index = self.cursor.fetch_add(1, Ordering::Relaxed);
+		if let Some(slot) = slots.get(index) {
+			*slot.payload.lock().unwrap_or_else(PoisonError::into_inner) = payload;
+		} else {
+			slots.push(Box::pin(PayloadSlot {
+				registration: Lazy::new(),
+				component: &*component,
+				handler,
+				payload: Mutex::new(payload),
+			}));
+		}
+
+		// SAFETY: Slots are pinned and only dropped along with `self`.
+		let slot: Pin<&'a PayloadSlot<Component, Payload>> =
+			unsafe { Pin::new_unchecked(&*(&*slots[index] as *const _)) };
+		drop(slots);
+
+		slot.registration
+			.get_or_create(|| CallbackRegistration::new(slot, PayloadSlot::invoke))
+	}
+}
+
+/// Releases unclaimed slots when dropped at the end of a render. See [`PayloadCallbackRegistrations::rewind`].
+pub struct RewoundPayloadCallbackRegistrations<'a, Component, Payload>(
+	&'a PayloadCallbackRegistrations<Component, Payload>,
+);
+
+impl<'a, Component, Payload> Drop for RewoundPayloadCallbackRegistrations<'a, Component, Payload> {
+	fn drop(&mut self) {
+		let released = {
+			let mut slots = self.0.slots.lock().unwrap_or_else(PoisonError::into_inner);
+			let claimed = self.0.cursor.load(Ordering::Relaxed).min(slots.len());
+			slots.split_off(claimed)
+		};
+		// Dropped outside the lock, since this waits for running handlers and drops user payloads.
+		drop(released)
+	}
+}
+
+/// A single payload slot. See [`PayloadCallbackRegistrations`].
+pub struct PayloadSlot<Component, Payload> {
+	// IMPORTANT: This field must be dropped first, to make sure `invoke` isn't running anymore.
+	registration: Lazy<CallbackRegistration<Self, fn(Event)>>,
+	component: *const Component,
+	handler: fn(Pin<&Component>, Event, Payload),
+	payload: Mutex<Payload>,
+}
+
+// SAFETY: `component` is only dereferenced as `&Component`, from whichever thread invokes the callback,
+// and stays valid for as long as the slot exists (since slots are dropped before the rest of the component).
+// Sending the slot thus only shares the component, which `Component: Sync` allows.
+// `payload` is only moved in and cloned out through its `Mutex`, which makes it `Send + Sync` for `Payload: Send`.
+// `handler` is a plain function pointer and `registration` only refers back to this slot.
+unsafe impl<Component: Sync, Payload: Send> Send for PayloadSlot<Component, Payload> {}
+// SAFETY: See above. No method gives out `&Payload` or `&mut Component`.
+unsafe impl<Component: Sync, Payload: Send> Sync for PayloadSlot<Component, Payload> {}
+
+impl<Component, Payload: Clone> PayloadSlot<Component, Payload> {
+	fn invoke(this: *const Self, event: Event) {
+		// SAFETY: The registration is dropped before the slot, and slots are dropped before the rest of the component.
+		let this = unsafe { &*this };
+		let payload = this
+			.payload
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.clone();
+		(this.handler)(
+			unsafe { Pin::new_unchecked(&*this.component) },
+			event,
+			payload,
+		)
+	}
+}
+
+/// Implemented by components for each event they declare with `emits name: Type,`, identified by a generated marker type.
+pub trait Emits<Event> {
+	/// The emitted event's payload type.
+	type Payload;
+}
+
+/// A key filter on a keyboard event binding (`on keydown[Ctrl+S] = …`).
+#[allow(clippy::struct_excessive_bools)]
+pub struct KeyFilter {
+	/// Compared to [`KeyboardEvent.key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) ASCII-case-insensitively.
+	pub key: &'static str,
+	pub ctrl: bool,
+	pub shift: bool,
+	pub alt: bool,
+	pub meta: bool,
+}
+
+impl KeyFilter {
+	/// Checks whether `event` is a keyboard event with a matching key and exactly the filter's modifier keys held.
+	#[must_use]
+	pub fn matches(&self, dom_events: &dyn DomEvents, event: &Event) -> bool {
+		dom_events
+			.keyboard_state(event)
+			.map_or(false, |state| self.matches_state(&state))
+	}
+
+	/// Checks whether `state` has a matching key and exactly the filter's modifier keys held.
+	#[must_use]
+	pub fn matches_state(&self, state: &KeyboardState) -> bool {
+		state.key.eq_ignore_ascii_case(self.key)
+			&& state.ctrl == self.ctrl
+			&& state.shift == self.shift
+			&& state.alt == self.alt
+			&& state.meta == self.meta
+	}
+}
+
+/// Validates a dynamic element name (`<{…} …>`) at render time.
+///
+/// Element names must not be empty or contain whitespace, control characters or any of `/`, `<`, `>`, `=`, `"` and `'`.
+///
+/// # Errors
+///
+/// Iff `name` is invalid, an [`InvalidElementName`](`crate::error::InvalidElementName`) is escalated.
+pub fn validate_element_name(name: &str) -> Result<&str, Escalation> {
+	if !name.is_empty()
+		&& !name
+			.chars()
+			.any(asteracea_proc_macro_definitions::is_invalid_element_name_char!())
+	{
+		Ok(name)
+	} else {
+		Err(InvalidElementName::new_and_log(name).escalate())
+	}
+}
+
+/// Calls a child component's `::new(…)` or `.render(…)` method (`call`),
+/// adding `frame` to the trace of any [`Escalation`] raised by it.
+///
+/// Panics aren't caught here, so this stays cheap on the render path.
+/// Unwinding [`Escalation`]s are still traced, but plain panics aren't.
+///
+/// # Errors
+///
+/// Iff `call` escalates, that [`Escalation`] is re-escalated with `frame` added.
+pub fn traced<T>(
+	frame: TraceFrame,
+	call: impl FnOnce() -> Result<T, Escalation>,
+) -> Result<T, Escalation> {
+	Escalation::traced(frame, call)
+}
+
 /// Automatically instantiates a [`Built::Builder`] for a type [`B: Built`](`Built`)
 /// that can be inferred from a phantom array.
 pub fn infer_builder<B: Built>(_phantom: [B; 0]) -> B::Builder {
@@ -1,7 +1,9 @@
 //! Common components.
 
+mod error_boundary;
 mod router;
 mod suspense;
 
+pub use error_boundary::{ErrorBoundary, ResetPolicy};
 pub use router::Router;
 pub use suspense::Suspense;
@@ -0,0 +1,85 @@
+use super::suspense::NoParentParameters;
+use crate::{
+	error::{Caught, Escalate, Escalation, Result},
+	include::render_callback::RenderOnce,
+};
+use bumpalo::Bump;
+use lignin::{Node, ThreadSafety};
+use std::{
+	any::Any,
+	cell::{Cell, RefCell},
+	panic::AssertUnwindSafe,
+};
+
+/// When an [`ErrorBoundary`] retries its `'content` after catching an [`Escalation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetPolicy {
+	/// Retry `'content` each time the [`ErrorBoundary`] is rendered.
+	Retry,
+	/// Keep rendering `'fallback` until [`ErrorBoundary::reset`] is called.
+	Manual,
+}
+
+asteracea::component! {
+	/// Renders `'content`, or `'fallback` if that escalates an error.
+	///
+	/// `'fallback` receives the caught escalation as slot argument, as in `'fallback: |caught| …`.
+	///
+	/// Caught panics are re-escalated, since unwinding may have left `'content`'s state inconsistent.
+	/// Escalated errors are caught also if they unwind the stack because the `"force-unwind"` feature is enabled.
+	pub ErrorBoundary(
+		/// When to retry `'content` after a caught escalation. Defaults to [`ResetPolicy::Retry`].
+		priv reset_policy: ResetPolicy = ResetPolicy::Retry,
+	)<S: 'bump + ThreadSafety>(
+		content: (NoParentParameters, Box<RenderOnce<'_, 'bump, S>>),
+		fallback: (
+			NoParentParameters,
+			Box<dyn '_ + FnOnce(&'bump Bump, &Caught<dyn Send + Any>) -> Result<Node<'bump, S>>>,
+		),
+	) -> Node::<'bump, S>
+
+	let self.caught = RefCell::<Option<Caught<dyn Send + Any>>>::new(None);
+	let self.reset_requested = Cell::<bool>::new(false);
+
+	{
+		if self.reset_requested.take() {
+			*self.caught.borrow_mut() = None;
+		}
+
+		if self.caught.borrow().is_none() || self.reset_policy == ResetPolicy::Retry {
+			match Escalation::catch_any(AssertUnwindSafe(|| (content.1)(bump))) {
+				Ok(rendered) => {
+					*self.caught.borrow_mut() = None;
+					return Ok(rendered);
+				}
+				Err(caught) if caught.was_panic() => return Err(caught.escalate()),
+				Err(caught) => *self.caught.borrow_mut() = Some(caught),
+			}
+		}
+
+		let caught = self.caught.borrow();
+		(fallback.1)(bump, caught.as_ref().expect("Always set when rendering `'fallback`."))?
+	}
+}
+
+impl ErrorBoundary {
+	/// Calls `f` with the escalation caught from `'content`, if it failed during the last render and this [`ErrorBoundary`] wasn't [reset](`ErrorBoundary::reset`) since.
+	///
+	/// # Panics
+	///
+	/// Iff `f` renders this [`ErrorBoundary`].
+	pub fn with_caught<R>(&self, f: impl FnOnce(Option<&Caught<dyn Send + Any>>) -> R) -> R {
+		if self.reset_requested.get() {
+			f(None)
+		} else {
+			f(self.caught.borrow().as_ref())
+		}
+	}
+
+	/// Allows `'content` to be retried on the next render.
+	///
+	/// This is only necessary with [`ResetPolicy::Manual`], and can also be called while `'fallback` is rendered.
+	pub fn reset(&self) {
+		self.reset_requested.set(true);
+	}
+}
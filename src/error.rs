@@ -4,19 +4,25 @@
 
 use std::{
 	any::Any,
-	borrow::Cow,
+	cell::Cell,
 	error::Error,
 	fmt::{self, Debug, Display, Formatter},
-	panic::{catch_unwind, panic_any, resume_unwind, UnwindSafe},
+	mem,
+	panic::{catch_unwind, resume_unwind, UnwindSafe},
+	process,
 	result::Result as stdResult,
-	writeln,
+	thread, writeln,
 };
 
 mod incompatible_runtime_dependency;
+mod invalid_element_name;
 mod runtime_dependency_missing;
+mod trace_frame;
 
 pub use incompatible_runtime_dependency::IncompatibleRuntimeDependency;
+pub use invalid_element_name::InvalidElementName;
 pub use runtime_dependency_missing::RuntimeDependencyMissing;
+pub use trace_frame::{Phase, TraceFrame};
 
 /// [`Result`](`core::result::Result`) shorthand for Asteracea-components.
 pub type Result<T> = stdResult<T, Escalation>;
@@ -38,37 +44,27 @@ pub type Result<T> = stdResult<T, Escalation>;
 ///
 /// > Unwinding notably isn't supported on `wasm32-unknown-unknown` as of Rust 1.49. This means any builds targeting the web natively will have to use implicit explicit GUI error escalation for now.
 ///
+/// Each child component element an [`Escalation`] passes through adds a [`TraceFrame`] to its trace, which is printed along with the error when it is displayed.
+/// (Plain panics are passed through unchanged instead.)
+///
+/// > With the `"error-abort"` feature, the process aborts right where an error is first escalated, so the trace printed along with it is always empty.
+///
 /// For expected errors and errors raised off-GUI (incl. in event handlers), [please see the book for recoverable error handling strategies.](`TODO`)
 #[derive(Debug)]
 #[allow(clippy::module_name_repetitions)]
 pub struct Escalation(Impl);
 
-impl<E: ?Sized> Caught<E> {
-	#[allow(
-		non_snake_case,
-		unused_mut,
-		unused_variables,
-		clippy::needless_pass_by_value
-	)]
-	#[doc(hidden)]
-	pub fn __Asteracea__with_traced_frame(mut self, frame: Cow<'static, str>) -> Self {
-		if let Some(trace) = &mut self.trace {
-			trace.push(frame);
-		} else {
-			self.trace = Some(vec![frame]);
-		}
-		self
-	}
-}
-
 impl From<Caught<dyn Send + Any>> for Escalation {
 	fn from(caught: Caught<dyn Send + Any>) -> Self {
 		let throwable = Throwable {
 			source: caught.boxed,
 			trace: caught.trace.unwrap_or_default(),
+			was_panic: caught.was_panic,
 		};
-		if cfg!(feature = "force-unwind") || caught.was_panic {
-			resume_unwind(Box::new(throwable))
+		if cfg!(feature = "error-abort") {
+			throwable.abort()
+		} else if cfg!(feature = "force-unwind") || caught.was_panic {
+			throwable.unwind()
 		} else {
 			#[cfg(not(feature = "force-unwind"))]
 			return Escalation(Impl::Extant(throwable));
@@ -85,9 +81,12 @@ impl<E: Send + Any> From<Caught<E>> for Escalation {
 		let throwable = Throwable {
 			source: caught.boxed,
 			trace: caught.trace.unwrap_or_default(),
+			was_panic: caught.was_panic,
 		};
-		if cfg!(feature = "force-unwind") || caught.was_panic {
-			resume_unwind(Box::new(throwable))
+		if cfg!(feature = "error-abort") {
+			throwable.abort()
+		} else if cfg!(feature = "force-unwind") || caught.was_panic {
+			throwable.unwind()
 		} else {
 			#[cfg(not(feature = "force-unwind"))]
 			return Escalation(Impl::Extant(throwable));
@@ -99,13 +98,90 @@ impl<E: Send + Any> From<Caught<E>> for Escalation {
 	}
 }
 
+impl Display for Escalation {
+	/// Displays the escalated error, followed by the component trace it was escalated through.
+	#[allow(unused_variables)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			#[cfg(not(feature = "force-unwind"))]
+			Impl::Extant(ref throwable) => Display::fmt(throwable, f),
+		}
+	}
+}
+
 #[allow(dead_code)]
 struct ErrorWrapper(Box<dyn SendAnyErrorCasting>);
 
 #[derive(Debug)]
 struct Throwable {
 	source: Box<dyn Send + Any>,
-	trace: Vec<Cow<'static, str>>,
+	trace: Vec<TraceFrame>,
+	/// Whether this originated from a panic rather than an escalated error, even while unwinding with `"force-unwind"`.
+	was_panic: bool,
+}
+thread_local! {
+	/// The [`TraceFrame`]s a [`Throwable`] passed through while unwinding this thread, if one is.
+	///
+	/// Collected by [`Escalation::traced`] without catching the unwind, then added to the [`Throwable`] when it is caught.
+	static UNWOUND_TRACE: Cell<Option<Vec<TraceFrame>>> = Cell::new(None);
+}
+
+impl Throwable {
+	/// Unwinds the stack with this [`Throwable`] as panic payload, collecting [`TraceFrame`]s on the way.
+	fn unwind(self) -> ! {
+		UNWOUND_TRACE.with(|trace| trace.set(Some(vec![])));
+		resume_unwind(Box::new(self))
+	}
+
+	/// Adds the [`TraceFrame`]s collected while this [`Throwable`] was unwinding.
+	///
+	/// Call this whenever a panic is caught (also if it's not a [`Throwable`], to discard stray frames).
+	fn unwound(mut self) -> Self {
+		self.trace.extend(take_unwound_trace());
+		self
+	}
+
+	/// Prints this escalation and its trace to stderr, then aborts the process.
+	///
+	/// Used in place of escalation iff the `"error-abort"` feature is enabled.
+	/// Since that happens where the error originates, the trace is always empty.
+	fn abort(self) -> ! {
+		eprintln!("GUI error escalation (aborting):\n{}", self);
+		process::abort()
+	}
+}
+impl Display for Throwable {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		fmt_source(&*self.source, f)?;
+		writeln!(f)?;
+		writeln!(f)?;
+		for frame in &self.trace {
+			writeln!(f, "from {}", frame)?;
+		}
+		Ok(())
+	}
+}
+
+/// Takes the [`TraceFrame`]s collected since the last [`Throwable`] started unwinding. See [`Throwable::unwound`].
+fn take_unwound_trace() -> Vec<TraceFrame> {
+	UNWOUND_TRACE
+		.try_with(Cell::take)
+		.ok()
+		.flatten()
+		.unwrap_or_default()
+}
+
+/// Displays an error or panic payload as well as possible.
+fn fmt_source(source: &(dyn Send + Any), f: &mut Formatter<'_>) -> fmt::Result {
+	if let Some(str) = source.downcast_ref::<&str>() {
+		Display::fmt(str, f)
+	} else if let Some(string) = source.downcast_ref::<String>() {
+		Display::fmt(string, f)
+	} else if let Some(wrapper) = source.downcast_ref::<ErrorWrapper>() {
+		Display::fmt(&wrapper.0, f)
+	} else {
+		writeln!(f, "type ID: {:?}", source.type_id())
+	}
 }
 
 #[derive(Debug)]
@@ -152,9 +228,12 @@ impl<E: SendAnyError> Escalate for E {
 		let throwable = Throwable {
 			source: Box::new(ErrorWrapper(Box::new(self))),
 			trace: vec![],
+			was_panic: false,
 		};
-		if cfg!(feature = "force-unwind") {
-			panic_any(Box::new(throwable));
+		if cfg!(feature = "error-abort") {
+			throwable.abort()
+		} else if cfg!(feature = "force-unwind") {
+			throwable.unwind()
 		} else {
 			#[cfg(not(feature = "force-unwind"))]
 			return Escalation(Impl::Extant(throwable));
@@ -188,7 +267,7 @@ impl<Ok, E: Escalate> EscalateResult for stdResult<Ok, E> {
 pub struct Caught<E: ?Sized> {
 	// An error or panic.
 	boxed: Box<E>,
-	trace: Option<Vec<Cow<'static, str>>>,
+	trace: Option<Vec<TraceFrame>>,
 	was_panic: bool,
 }
 impl<E: ?Sized> Caught<E> {
@@ -197,6 +276,24 @@ impl<E: ?Sized> Caught<E> {
 	pub fn into_boxed(self) -> Box<E> {
 		self.boxed
 	}
+
+	/// Whether this was caught from a panic rather than an escalated error.
+	///
+	/// Escalated errors aren't panics by this measure even if they unwound the stack because `"force-unwind"` is enabled.
+	///
+	/// Re-escalating a [`Caught`] for which this is `true` always resumes unwinding.
+	#[must_use]
+	pub fn was_panic(&self) -> bool {
+		self.was_panic
+	}
+
+	/// The child component expressions this error or panic was escalated through, innermost first.
+	///
+	/// This is empty if the panic wasn't caught by any component's child component expression before.
+	#[must_use]
+	pub fn trace(&self) -> &[TraceFrame] {
+		self.trace.as_deref().unwrap_or_default()
+	}
 }
 impl<E> Caught<E> {
 	/// Unwraps the boxed error or panic by value, discarding the trace.
@@ -207,19 +304,11 @@ impl<E> Caught<E> {
 }
 impl Debug for Caught<dyn Send + Any> {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		if let Some(str) = self.boxed.downcast_ref::<&str>() {
-			Display::fmt(str, f)?;
-		} else if let Some(string) = self.boxed.downcast_ref::<String>() {
-			Display::fmt(string, f)?;
-		} else if let Some(wrapper) = self.boxed.downcast_ref::<ErrorWrapper>() {
-			Display::fmt(&wrapper.0, f)?;
-		} else {
-			writeln!(f, "type ID: {:?}", self.boxed.type_id())?;
-		}
+		fmt_source(&*self.boxed, f)?;
 		writeln!(f)?;
 		writeln!(f)?;
 		for frame in self.trace.iter().flatten() {
-			writeln!(f, "in {}", frame)?;
+			writeln!(f, "from {}", frame)?;
 		}
 		Ok(())
 	}
@@ -230,7 +319,7 @@ impl<E: Debug> Debug for Caught<E> {
 		writeln!(f)?;
 		writeln!(f)?;
 		for frame in self.trace.iter().flatten() {
-			writeln!(f, "in {}", frame)?;
+			writeln!(f, "from {}", frame)?;
 		}
 		Ok(())
 	}
@@ -242,7 +331,7 @@ impl<E: Display> Display for Caught<E> {
 		writeln!(f)?;
 		writeln!(f)?;
 		for frame in self.trace.iter().flatten() {
-			writeln!(f, "in {}", frame)?;
+			writeln!(f, "from {}", frame)?;
 		}
 		Ok(())
 	}
@@ -262,6 +351,42 @@ impl Escalate for Caught<dyn Send + Any> {
 }
 
 impl Escalation {
+	/// Calls a child component's `::new(…)` or `.render(…)` method (`call`), adding `frame` to the trace of any [`Escalation`] raised by it.
+	///
+	/// This doesn't catch panics: Unwinding [`Escalation`]s are traced through a drop guard instead, and plain panics are left untraced.
+	pub(crate) fn traced<T>(frame: TraceFrame, call: impl FnOnce() -> Result<T>) -> Result<T> {
+		/// Adds its frame to the [`UNWOUND_TRACE`] iff dropped while a [`Throwable`] is unwinding.
+		struct Unwinding(TraceFrame);
+		impl Drop for Unwinding {
+			fn drop(&mut self) {
+				if thread::panicking() {
+					// Ignored if the thread is shutting down.
+					let _ = UNWOUND_TRACE.try_with(|trace| {
+						let mut frames = trace.take();
+						if let Some(frames) = &mut frames {
+							frames.push(self.0);
+						}
+						trace.set(frames);
+					});
+				}
+			}
+		}
+
+		let unwinding = Unwinding(frame);
+		let result = call();
+		mem::forget(unwinding);
+		match result {
+			Ok(t) => Ok(t),
+			#[cfg(feature = "force-unwind")]
+			Err(_) => unreachable!(),
+			#[cfg(not(feature = "force-unwind"))]
+			Err(Escalation(Impl::Extant(mut throwable))) => {
+				throwable.trace.push(frame);
+				Err(Escalation(Impl::Extant(throwable)))
+			}
+		}
+	}
+
 	/// Catches any [`Escalation`] currently unwinding the stack.
 	///
 	/// Plain panics are considered to also be escalations.
@@ -279,22 +404,33 @@ impl Escalation {
 			#[cfg(feature = "force-unwind")]
 			Ok(Err(_)) => unreachable!(),
 			#[cfg(not(feature = "force-unwind"))]
-			Ok(Err(Escalation(Impl::Extant(Throwable { source, trace })))) => Err(Caught {
+			Ok(Err(Escalation(Impl::Extant(Throwable {
+				source,
+				trace,
+				was_panic,
+			})))) => Err(Caught {
 				boxed: source,
 				trace: Some(trace),
-				was_panic: false,
+				was_panic,
 			}),
 			Err(panic) => Err(match Box::<dyn Send + Any>::downcast::<Throwable>(panic) {
-				Ok(thrown) => Caught {
-					boxed: thrown.source,
-					trace: Some(thrown.trace),
-					was_panic: true,
-				},
-				Err(panic) => Caught {
-					boxed: panic,
-					trace: None,
-					was_panic: true,
-				},
+				Ok(thrown) => {
+					let thrown = thrown.unwound();
+					Caught {
+						boxed: thrown.source,
+						trace: Some(thrown.trace),
+						was_panic: thrown.was_panic,
+					}
+				}
+				Err(panic) => {
+					// Discards frames collected for a plain panic.
+					drop(take_unwound_trace());
+					Caught {
+						boxed: panic,
+						trace: None,
+						was_panic: true,
+					}
+				}
 			}),
 		}
 	}
@@ -311,23 +447,24 @@ impl Escalation {
 		F: UnwindSafe + FnOnce() -> Result<T>,
 		E: 'static,
 	{
-		let (thrown, was_panic) = match catch_unwind(f) {
+		let thrown = match catch_unwind(f) {
 			Ok(Ok(t)) => return Ok(Ok(t)),
 			#[cfg(feature = "force-unwind")]
 			Ok(Err(_)) => unreachable!(),
 			#[cfg(not(feature = "force-unwind"))]
-			Ok(Err(Escalation(Impl::Extant(thrown)))) => (thrown, false),
+			Ok(Err(Escalation(Impl::Extant(thrown)))) => thrown,
 			Err(panic) => match Box::<dyn Send + Any>::downcast::<Throwable>(panic) {
-				Ok(thrown) => (*thrown, true),
+				Ok(thrown) => thrown.unwound(),
 				Err(panic) => {
 					// Not instrumented.
 					match Box::<dyn Send + Any>::downcast(panic) {
 						Ok(e) => {
+							drop(take_unwound_trace());
 							return Err(Caught {
 								boxed: e,
 								trace: None,
 								was_panic: true,
-							})
+							});
 						}
 						Err(panic) => resume_unwind(panic),
 					}
@@ -340,7 +477,7 @@ impl Escalation {
 					return Err(Caught {
 						boxed: caught,
 						trace: Some(thrown.trace),
-						was_panic,
+						was_panic: thrown.was_panic,
 					})
 				}
 				Err(wrapper) => wrapper,
@@ -350,7 +487,7 @@ impl Escalation {
 					return Err(Caught {
 						boxed: e,
 						trace: Some(thrown.trace),
-						was_panic,
+						was_panic: thrown.was_panic,
 					})
 				}
 				Err(uncaught) => uncaught,
@@ -359,9 +496,10 @@ impl Escalation {
 		let throwable = Throwable {
 			source: uncaught,
 			trace: thrown.trace,
+			was_panic: thrown.was_panic,
 		};
-		if cfg!(feature = "force-unwind") || was_panic {
-			resume_unwind(Box::new(throwable))
+		if cfg!(feature = "force-unwind") || throwable.was_panic {
+			throwable.unwind()
 		} else {
 			#[cfg(not(feature = "force-unwind"))]
 			{
@@ -0,0 +1,42 @@
+use std::{
+	error::Error,
+	fmt::{self, Debug, Display, Formatter},
+};
+
+/// Escalated when a dynamic element name (`<{…} …>`) is empty or contains spaces or other invalid characters.
+pub struct InvalidElementName {
+	name: String,
+}
+
+impl InvalidElementName {
+	/// Creates a new instance of [`InvalidElementName`] and,
+	/// with the `"tracing"` feature enabled, logs this event as error.
+	#[must_use]
+	pub fn new_and_log(name: &str) -> Self {
+		let this = Self {
+			name: name.to_owned(),
+		};
+		crate::__::tracing::error!("{}", &this);
+		this
+	}
+
+	/// The rejected element name.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+}
+
+impl Error for InvalidElementName {}
+impl Debug for InvalidElementName {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("InvalidElementName")
+			.field("name", &self.name)
+			.finish()
+	}
+}
+impl Display for InvalidElementName {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Invalid element name {:?}", self.name)
+	}
+}
@@ -0,0 +1,102 @@
+use std::fmt::{self, Display, Formatter};
+
+/// One entry in an [`Escalation`](`super::Escalation`)'s trace:
+/// The child component expression (`<*…>`) through which it was escalated.
+///
+/// Frames are recorded innermost first and displayed like `Child::render (in Parent) at src/parent.rs:12:3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceFrame {
+	callee: &'static str,
+	component: &'static str,
+	phase: Phase,
+	file: &'static str,
+	line: u32,
+	column: u32,
+}
+
+/// The component method an [`Escalation`](`super::Escalation`) was traced through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+	/// `::new(…)`, i.e. while constructing the child component.
+	New,
+	/// `.render(…)`, i.e. while rendering the child component.
+	Render,
+}
+
+impl TraceFrame {
+	#[allow(non_snake_case)]
+	#[doc(hidden)]
+	#[must_use]
+	pub const fn __Asteracea__new(
+		callee: &'static str,
+		component: &'static str,
+		phase: Phase,
+		file: &'static str,
+		line: u32,
+		column: u32,
+	) -> Self {
+		Self {
+			callee,
+			component,
+			phase,
+			file,
+			line,
+			column,
+		}
+	}
+
+	/// The child component (path or `{…}` instance expression, as written) whose method failed.
+	#[must_use]
+	pub fn callee(&self) -> &'static str {
+		self.callee
+	}
+
+	/// The name of the component containing the failed child component expression.
+	#[must_use]
+	pub fn component(&self) -> &'static str {
+		self.component
+	}
+
+	/// Whether the [callee](`TraceFrame::callee`) failed to construct or to render.
+	#[must_use]
+	pub fn phase(&self) -> Phase {
+		self.phase
+	}
+
+	/// The source file containing the failed child component expression, as reported by [`file!`].
+	#[must_use]
+	pub fn file(&self) -> &'static str {
+		self.file
+	}
+
+	/// The line of the failed child component expression, as reported by [`line!`].
+	#[must_use]
+	pub fn line(&self) -> u32 {
+		self.line
+	}
+
+	/// The column of the failed child component expression, as reported by [`column!`].
+	#[must_use]
+	pub fn column(&self) -> u32 {
+		self.column
+	}
+}
+
+impl Display for TraceFrame {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}::{} (in {}) at {}:{}:{}",
+			self.callee, self.phase, self.component, self.file, self.line, self.column
+		)
+	}
+}
+
+impl Display for Phase {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Phase::New => "new",
+			Phase::Render => "render",
+		})
+	}
+}
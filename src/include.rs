@@ -9,7 +9,15 @@
 pub mod __for_;
 pub mod async_;
 mod defer;
+mod dom_binding;
+mod dyn_branch;
+mod emitter;
+mod event_tasks;
 pub mod render_callback;
 
 pub use __for_::For;
 pub use defer::Defer;
+pub use dom_binding::{DomBinding, PropertyTarget};
+pub use dyn_branch::DynBranch;
+pub use emitter::Emitter;
+pub use event_tasks::{EventFuture, EventTasks};
@@ -0,0 +1,192 @@
+use crate::{
+	services::{DomProperties, ServiceHandle},
+	Property,
+};
+use lignin::{web::HtmlElement, CallbackRegistration, DomRef};
+use std::{
+	marker::PhantomPinned,
+	pin::Pin,
+	sync::{Mutex, PoisonError},
+};
+use try_lazy_init::Lazy;
+
+/// Storage for a DOM binding (`ref self.name`) on an element, text or comment.
+///
+/// While the bound node is present in the DOM, a clone of its handle can be retrieved via [`.get()`](`DomBinding::get`).
+///
+/// Elements with property bindings (`.:property = …`) also use a [`DomBinding`] (possibly a hidden one)
+/// to assign their properties through [`DomProperties`] whenever the element is added to the DOM.
+pub struct DomBinding<T> {
+	// Declared first so that the callback is unregistered before `state` is dropped.
+	registration: Lazy<CallbackRegistration<Self, fn(DomRef<&'_ T>)>>,
+	state: Mutex<State<T>>,
+	_pinned: PhantomPinned,
+}
+
+struct State<T> {
+	current: Option<T>,
+	properties: Option<AssignedProperties<T>>,
+}
+
+struct AssignedProperties<T> {
+	dom: ServiceHandle<dyn DomProperties>,
+	set_property: fn(&T, &dyn DomProperties, &str, Property<'_>),
+	values: Vec<(&'static str, OwnedProperty)>,
+}
+
+#[derive(Clone)]
+enum OwnedProperty {
+	Bool(bool),
+	String(String),
+}
+
+impl OwnedProperty {
+	fn as_property(&self) -> Property<'_> {
+		match self {
+			OwnedProperty::Bool(value) => Property::Bool(*value),
+			OwnedProperty::String(value) => Property::Str(value),
+		}
+	}
+}
+
+impl<'a> From<Property<'a>> for OwnedProperty {
+	fn from(property: Property<'a>) -> Self {
+		match property {
+			Property::Bool(value) => OwnedProperty::Bool(value),
+			Property::Str(value) => OwnedProperty::String(value.to_owned()),
+		}
+	}
+}
+
+/// DOM node types that property bindings (`.:property = …`) can be assigned on.
+pub trait PropertyTarget {
+	/// Assigns `value` to this node's property `name` through `dom`.
+	fn set_property(&self, dom: &dyn DomProperties, name: &str, value: Property<'_>);
+}
+
+impl PropertyTarget for HtmlElement {
+	fn set_property(&self, dom: &dyn DomProperties, name: &str, value: Property<'_>) {
+		dom.set_property(self, name, value)
+	}
+}
+
+impl<T> Default for DomBinding<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> DomBinding<T> {
+	/// Creates a new [`DomBinding<T>`] that isn't bound to a node yet.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			registration: Lazy::new(),
+			state: Mutex::new(State {
+				current: None,
+				properties: None,
+			}),
+			_pinned: PhantomPinned,
+		}
+	}
+
+	/// Retrieves the bound node, iff it is currently present in the DOM.
+	#[must_use]
+	pub fn get(self: Pin<&Self>) -> Option<T>
+	where
+		T: Clone,
+	{
+		self.state
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.current
+			.clone()
+	}
+
+	#[allow(non_snake_case)]
+	#[doc(hidden)]
+	pub fn __Asteracea__registration(
+		self: Pin<&Self>,
+	) -> &CallbackRegistration<Self, fn(DomRef<&'_ T>)>
+	where
+		T: Clone,
+	{
+		self.registration
+			.get_or_create(|| CallbackRegistration::new(self, Self::on_dom_ref))
+	}
+
+	/// Stores `properties` to be assigned whenever the node is added to the DOM,
+	/// and assigns them right away if it's currently present.
+	///
+	/// Does nothing without `dom`.
+	#[allow(non_snake_case)]
+	#[doc(hidden)]
+	pub fn __Asteracea__assign_properties(
+		self: Pin<&Self>,
+		dom: Option<&ServiceHandle<dyn DomProperties>>,
+		properties: &[(&'static str, Property<'_>)],
+	) where
+		T: PropertyTarget + Clone,
+	{
+		let dom = match dom {
+			Some(dom) => dom,
+			None => return,
+		};
+
+		// The lock is released before assigning, in case `dom` renders or binds reentrantly.
+		let current = {
+			let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+			let state = &mut *state;
+			let assigned = state.properties.get_or_insert_with(|| AssignedProperties {
+				dom: dom.clone(),
+				set_property: T::set_property,
+				values: Vec::with_capacity(properties.len()),
+			});
+			assigned.values.clear();
+			assigned.values.extend(
+				properties
+					.iter()
+					.map(|&(name, value)| (name, OwnedProperty::from(value))),
+			);
+			state.current.clone()
+		};
+
+		if let Some(current) = current {
+			for &(name, value) in properties {
+				current.set_property(&**dom, name, value);
+			}
+		}
+	}
+
+	fn on_dom_ref(this: *const Self, dom_ref: DomRef<&'_ T>)
+	where
+		T: Clone,
+	{
+		let this = unsafe {
+			//SAFETY: The registration is dropped before `this`, so the pointer is valid whenever the callback is invoked.
+			&*this
+		};
+		let added = match dom_ref {
+			DomRef::Added(node) => Some(node),
+			DomRef::Removing(_) => None,
+		};
+		let assigned = {
+			let mut state = this.state.lock().unwrap_or_else(PoisonError::into_inner);
+			state.current = added.cloned();
+			added.and(state.properties.as_ref()).map(|assigned| {
+				(
+					assigned.dom.clone(),
+					assigned.set_property,
+					assigned.values.clone(),
+				)
+			})
+		};
+
+		// Assigned without holding the lock, as in `__Asteracea__assign_properties`.
+		if let (Some(node), Some((dom, set_property, values))) = (added, assigned) {
+			for (name, value) in &values {
+				set_property(node, &*dom, name, value.as_property());
+			}
+		}
+	}
+}
@@ -0,0 +1,164 @@
+use crate::error::{Escalate, Escalation};
+use rhizome::sync::{DynValue, NodeHandle};
+use std::{
+	any::TypeId,
+	cell::UnsafeCell,
+	error::Error,
+	fmt::{self, Display, Formatter},
+	pin::Pin,
+	result::Result,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// [`DynBranch::state`] while the branch's `Storage` is absent.
+const EMPTY: usize = 0;
+/// [`DynBranch::state`] while the branch's `Storage` is constructed or dropped.
+const BUSY: usize = 1;
+/// [`DynBranch::state`] while the branch's `Storage` is present and not rendered.
+///
+/// Each ongoing render adds `1`.
+const CONSTRUCTED: usize = 2;
+
+/// Storage for a single branch of a `dyn if` or `dyn match` expression.
+///
+/// The branch's storage is constructed lazily when the branch is rendered,
+/// and only dropped when [`.clear()`](`DynBranch::clear`) is called (or along with the [`DynBranch`] instance).
+///
+/// Like [`Defer`](`super::Defer`), this doesn't lock, so it's [`Sync`] whenever `Storage` is.
+pub struct DynBranch<Storage> {
+	node: NodeHandle<TypeId, TypeId, DynValue>,
+	state: AtomicUsize,
+	storage: UnsafeCell<Option<Storage>>,
+}
+
+//SAFETY: `storage` is only written while `state` is `BUSY`, which excludes any other access to it.
+// Otherwise, it's only shared as `Pin<&Storage>`.
+unsafe impl<Storage: Send + Sync> Sync for DynBranch<Storage> {}
+
+impl<Storage> DynBranch<Storage> {
+	/// Creates a new empty [`DynBranch<Storage>`] that constructs its `Storage` as child of `node`.
+	#[must_use]
+	pub fn new(node: NodeHandle<TypeId, TypeId, DynValue>) -> Self {
+		Self {
+			node,
+			state: AtomicUsize::new(EMPTY),
+			storage: UnsafeCell::new(None),
+		}
+	}
+
+	/// Renders this branch's content via `render`, constructing the branch's `Storage` first if necessary.
+	///
+	/// The `Storage` may be rendered reentrantly.
+	///
+	/// # Errors
+	///
+	/// Iff `construct` or `render` fail, that [`Escalation`] is returned verbatim.
+	///
+	/// Failed construction leaves the branch empty, so it is attempted again during the next render.
+	///
+	/// Iff the `Storage` is being constructed or dropped at the same time, a less specific [`Escalation`] is returned.
+	pub fn render_or_construct<R>(
+		self: Pin<&Self>,
+		construct: impl FnOnce(NodeHandle<TypeId, TypeId, DynValue>) -> Result<Storage, Escalation>,
+		render: impl FnOnce(Pin<&Storage>) -> Result<R, Escalation>,
+	) -> Result<R, Escalation> {
+		#[derive(Debug)]
+		struct DynBranchBusy;
+		impl Error for DynBranchBusy {}
+		impl Display for DynBranchBusy {
+			fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+				f.write_str("`dyn` branch storage was rendered while being constructed or dropped.")
+			}
+		}
+
+		/// Resets or releases [`DynBranch::state`], also while unwinding.
+		struct Release<'a>(&'a AtomicUsize, fn(&AtomicUsize));
+		impl Drop for Release<'_> {
+			fn drop(&mut self) {
+				(self.1)(self.0)
+			}
+		}
+
+		let mut state = self.state.load(Ordering::Acquire);
+		loop {
+			match state {
+				EMPTY => match self.state.compare_exchange_weak(
+					EMPTY,
+					BUSY,
+					Ordering::Acquire,
+					Ordering::Acquire,
+				) {
+					Ok(_) => {
+						let reset =
+							Release(&self.state, |state| state.store(EMPTY, Ordering::Release));
+						let storage = construct(self.node.clone_handle())?;
+						unsafe {
+							//SAFETY: `state` is `BUSY`, so nothing else accesses `storage`.
+							*self.storage.get() = Some(storage);
+						}
+						std::mem::forget(reset);
+						self.state.store(CONSTRUCTED, Ordering::Release);
+						state = CONSTRUCTED;
+					}
+					Err(current) => state = current,
+				},
+				BUSY => return Err(DynBranchBusy.escalate()),
+				rendering => match self.state.compare_exchange_weak(
+					rendering,
+					rendering + 1,
+					Ordering::Acquire,
+					Ordering::Acquire,
+				) {
+					Ok(_) => {
+						let _release = Release(&self.state, |state| {
+							state.fetch_sub(1, Ordering::Release);
+						});
+						let storage = match unsafe {
+							//SAFETY: `state` stays above `CONSTRUCTED` until `_release` is dropped, so `storage` isn't written meanwhile.
+							&*self.storage.get()
+						} {
+							Some(storage) => storage,
+							None => unreachable!(),
+						};
+						return render(unsafe {
+							//SAFETY: The storage is never moved out of, only dropped in place, and `self` is pinned.
+							Pin::new_unchecked(storage)
+						});
+					}
+					Err(current) => state = current,
+				},
+			}
+		}
+	}
+
+	/// Drops this branch's `Storage`, if constructed.
+	///
+	/// # Panics
+	///
+	/// Iff called while this branch is being constructed, rendered or dropped.
+	pub fn clear(self: Pin<&Self>) {
+		match self
+			.state
+			.compare_exchange(CONSTRUCTED, BUSY, Ordering::Acquire, Ordering::Acquire)
+		{
+			Ok(_) => {
+				let storage = unsafe {
+					//SAFETY: `state` is `BUSY`, so nothing else accesses `storage`.
+					&mut *self.storage.get()
+				};
+				// Assignment drops the previous value in place, which upholds the pinning guarantee.
+				// Should that panic, the branch stays `BUSY` and is only dropped along with the `DynBranch`.
+				*storage = None;
+				self.state.store(EMPTY, Ordering::Release);
+			}
+			Err(EMPTY) => (),
+			Err(_) => panic!("`dyn` branch storage cleared while in use"),
+		}
+	}
+
+	/// Checks whether this branch's `Storage` is currently constructed.
+	#[must_use]
+	pub fn is_constructed(self: Pin<&Self>) -> bool {
+		self.state.load(Ordering::Acquire) >= CONSTRUCTED
+	}
+}
@@ -0,0 +1,27 @@
+use lignin::{CallbackRef, ThreadBound};
+
+/// An outgoing event of a child component, declared with `emits name: Type,`.
+///
+/// Parent components bind it with `<*Child on name = handler>`, after which it arrives as optional `.render` argument `name`.
+/// Calling [`.emit(…)`](`Emitter::emit`) after the parent was dropped does nothing.
+pub struct Emitter<T>(CallbackRef<ThreadBound, fn(T)>);
+
+impl<T> Emitter<T> {
+	/// Creates a new [`Emitter<T>`] invoking `callback`.
+	#[must_use]
+	pub fn new(callback: CallbackRef<ThreadBound, fn(T)>) -> Self {
+		Self(callback)
+	}
+
+	/// Invokes the parent's handler with `value`.
+	pub fn emit(self, value: T) {
+		self.0.call(value)
+	}
+}
+
+impl<T> Clone for Emitter<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+impl<T> Copy for Emitter<T> {}
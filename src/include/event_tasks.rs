@@ -0,0 +1,140 @@
+use crate::{
+	error::{Escalate, IncompatibleRuntimeDependency, Result, RuntimeDependencyMissing},
+	services::{EventRuntime, Invalidator, ServiceHandle},
+};
+use rhizome::sync::{DynValue, Extract, Node};
+use std::{
+	any::TypeId,
+	cell::RefCell,
+	future::Future,
+	mem,
+	pin::Pin,
+	process,
+	rc::Rc,
+	task::{Context, Poll},
+};
+
+struct Task {
+	/// Not actually `'static`. See [`EventTasks::spawn`].
+	future: RefCell<Option<Pin<Box<dyn Future<Output = ()>>>>>,
+}
+
+/// Storage for futures started by `async fn` event handlers.
+///
+/// The futures themselves are owned by this instance and are dropped along with it,
+/// even if the [`EventRuntime`] still holds matching [`EventFuture`]s at that point.
+///
+/// Dropping an [`EventTasks`] instance while one of its futures is being polled (i.e. dropping a component from within its own `async` event handler)
+/// **aborts the process**, as that future still borrows the component.
+///
+/// This type is neither [`Send`] nor [`Sync`], so `async` event handlers are unavailable in components that must render `-> Sync`.
+pub struct EventTasks {
+	runtime: ServiceHandle<dyn EventRuntime>,
+	invalidator: Option<ServiceHandle<dyn Invalidator>>,
+	tasks: RefCell<Vec<Rc<Task>>>,
+}
+
+impl EventTasks {
+	/// Creates a new [`EventTasks`] instance with the [`EventRuntime`] and (optionally) [`Invalidator`] injected at `node`.
+	///
+	/// # Errors
+	///
+	/// Iff no [`EventRuntime`] is available or one of the dependencies is incompatible, an [`Escalation`](`crate::error::Escalation`) is raised.
+	pub fn new(node: Pin<&Node<TypeId, TypeId, DynValue>>) -> Result<Self> {
+		let runtime = <dyn EventRuntime as Extract>::extract(node)
+			.map_err(IncompatibleRuntimeDependency::<dyn EventRuntime>::new_and_log)
+			.map_err(Escalate::escalate)?
+			.ok_or_else(RuntimeDependencyMissing::<dyn EventRuntime>::new_and_log)
+			.map_err(Escalate::escalate)?;
+		let invalidator = <dyn Invalidator as Extract>::extract(node)
+			.map_err(IncompatibleRuntimeDependency::<dyn Invalidator>::new_and_log)
+			.map_err(Escalate::escalate)?;
+		Ok(Self {
+			runtime,
+			invalidator,
+			tasks: RefCell::default(),
+		})
+	}
+
+	/// Stores `future` and schedules a matching [`EventFuture`] on the [`EventRuntime`].
+	///
+	/// # Safety
+	///
+	/// Anything borrowed by `future` must outlive this [`EventTasks`] instance.
+	///
+	/// > Generated components ensure this by dropping their [`EventTasks`] before any other fields.
+	pub unsafe fn spawn<'a>(&self, future: impl 'a + Future<Output = ()>) {
+		let future: Pin<Box<dyn 'a + Future<Output = ()>>> = Box::pin(future);
+		//SAFETY: Only the lifetime is erased. The future is dropped no later than this `EventTasks` (see `Drop` below),
+		// which the caller guarantees to happen while anything it borrows is still alive.
+		let future: Pin<Box<dyn 'static + Future<Output = ()>>> = mem::transmute(future);
+		let task = Rc::new(Task {
+			future: RefCell::new(Some(future)),
+		});
+
+		{
+			let mut tasks = self.tasks.borrow_mut();
+			// Tasks that are being polled right now are still running.
+			tasks.retain(|task| {
+				task.future
+					.try_borrow()
+					.map_or(true, |future| future.is_some())
+			});
+			tasks.push(Rc::clone(&task));
+		}
+
+		self.runtime.start_event_future(EventFuture {
+			task,
+			invalidator: self.invalidator.clone(),
+		});
+	}
+}
+
+impl Drop for EventTasks {
+	fn drop(&mut self) {
+		for task in self.tasks.get_mut().drain(..) {
+			if let Ok(mut future) = task.future.try_borrow_mut() {
+				*future = None;
+			} else {
+				// The future is being polled right now and still borrows the component, which is being dropped.
+				// Unwinding wouldn't help, since that would drop the rest of the component before the future too.
+				eprintln!("Asteracea: A component was dropped from within one of its `async` event handlers (aborting).");
+				process::abort()
+			}
+		}
+	}
+}
+
+/// A handle to a future started by an `async fn` event handler. Scheduled through [`EventRuntime`].
+///
+/// Once the handler's future completes, the component's [`Invalidator`] (if any) is called.
+/// If the component is dropped first, this future completes immediately without doing so.
+///
+/// Since event handlers can borrow their component, this type is neither [`Send`] nor [`Sync`].
+pub struct EventFuture {
+	task: Rc<Task>,
+	invalidator: Option<ServiceHandle<dyn Invalidator>>,
+}
+
+impl Future for EventFuture {
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut future = self.task.future.borrow_mut();
+		let poll = match &mut *future {
+			None => return Poll::Ready(()),
+			Some(future) => future.as_mut().poll(cx),
+		};
+		match poll {
+			Poll::Pending => Poll::Pending,
+			Poll::Ready(()) => {
+				*future = None;
+				drop(future);
+				if let Some(invalidator) = &self.invalidator {
+					invalidator.invalidate();
+				}
+				Poll::Ready(())
+			}
+		}
+	}
+}
@@ -6,7 +6,9 @@
 //!
 //! ## `"error-abort"`
 //!
-//! Reserved. Will be used to abort-the process on GUI error escalation.
+//! Abort the process on GUI error escalation, after printing the escalated error and its trace to stderr.
+//!
+//! This takes precedence over `"force-unwind"`.
 //!
 //! ## `"force-unwind"`
 //!
@@ -42,6 +44,10 @@ pub use try_lazy_init;
 #[doc = include_str!("../README.md")]
 mod readme {}
 
+#[cfg(doctest)]
+#[doc = include_str!("../tests/compile_fail.md")]
+mod compile_fail {}
+
 pub mod components;
 pub mod error;
 pub mod include;
@@ -72,6 +78,46 @@ impl<'a> ConditionalAttributeValue<'a> for Option<&'a str> {
 	}
 }
 
+/// A DOM property value, as assigned through property bindings (`.:property = …`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property<'a> {
+	/// A boolean property, like `checked`.
+	Bool(bool),
+	/// A string property, like `value`.
+	Str(&'a str),
+}
+
+impl<'a> Property<'a> {
+	/// The value of the property's equivalent attribute, or [`None`] to omit it.
+	#[must_use]
+	pub fn into_attribute_value(self) -> Option<&'a str> {
+		match self {
+			Property::Bool(value) => value.into_str_option(),
+			Property::Str(value) => Some(value),
+		}
+	}
+}
+
+/// Types implementing this trait can be accepted as value by property bindings (`.:property = …`).
+pub trait PropertyValue<'a> {
+	/// Converts the value into a [`Property`].
+	fn into_property(self) -> Property<'a>;
+}
+
+impl PropertyValue<'static> for bool {
+	#[inline]
+	fn into_property(self) -> Property<'static> {
+		Property::Bool(self)
+	}
+}
+
+impl<'a> PropertyValue<'a> for &'a str {
+	#[inline]
+	fn into_property(self) -> Property<'a> {
+		Property::Str(self)
+	}
+}
+
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub mod __;
@@ -0,0 +1,67 @@
+use lignin::web::Event;
+use rhizome::sync::derive_dependency;
+
+/// A resource used by event bindings with modifiers (`prevent_default`, `stop_propagation`) or key filters (`keydown[Ctrl+S]`)
+/// to inspect and manipulate DOM events, which [`lignin`] can't do by itself.
+///
+/// Components with such event bindings require this service:
+/// Their construction escalates a [`RuntimeDependencyMissing`](`crate::error::RuntimeDependencyMissing`) without it.
+///
+/// # Implementation Guidelines
+///
+/// In a browser, forward to the matching [`Event`](https://developer.mozilla.org/en-US/docs/Web/API/Event)
+/// and [`KeyboardEvent`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent) methods and properties.
+///
+/// These methods are called synchronously while the event is dispatched, before the handler runs.
+pub trait DomEvents {
+	/// Cancels `event`'s default action, as with `event.preventDefault()`.
+	fn prevent_default(&self, event: &Event);
+
+	/// Stops `event` from propagating further, as with `event.stopPropagation()`.
+	fn stop_propagation(&self, event: &Event);
+
+	/// Retrieves the key and modifier state of `event`, iff it is a keyboard event.
+	fn keyboard_state(&self, event: &Event) -> Option<KeyboardState>;
+
+	/// Applies an event binding's `modifiers` to `event`, right before its handler runs.
+	///
+	/// By default, this calls [`.prevent_default(event)`](`DomEvents::prevent_default`) and then [`.stop_propagation(event)`](`DomEvents::stop_propagation`) as requested.
+	fn apply_modifiers(&self, event: &Event, modifiers: EventModifiers) {
+		if modifiers.prevent_default {
+			self.prevent_default(event);
+		}
+		if modifiers.stop_propagation {
+			self.stop_propagation(event);
+		}
+	}
+}
+derive_dependency!(dyn DomEvents);
+
+/// The modifiers of an event binding (`on ⟦prevent_default⟧ ⟦stop_propagation⟧ name = …`). See [`DomEvents::apply_modifiers`].
+///
+/// Event bindings with modifiers encode them as constant that's applied before their handler runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EventModifiers {
+	/// Whether to cancel the event's default action.
+	///
+	/// Event bindings with this modifier are registered as non-passive listeners.
+	pub prevent_default: bool,
+	/// Whether to stop the event from propagating further.
+	pub stop_propagation: bool,
+}
+
+/// The key and modifier state of a keyboard event. See [`DomEvents::keyboard_state`].
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardState {
+	/// The event's [`KeyboardEvent.key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key).
+	pub key: String,
+	/// Whether <kbd>Ctrl</kbd> was held.
+	pub ctrl: bool,
+	/// Whether <kbd>Shift</kbd> was held.
+	pub shift: bool,
+	/// Whether <kbd>Alt</kbd> was held.
+	pub alt: bool,
+	/// Whether <kbd>Meta</kbd> was held.
+	pub meta: bool,
+}
@@ -0,0 +1,26 @@
+use crate::Property;
+use lignin::web::HtmlElement;
+use rhizome::sync::derive_dependency;
+
+/// A resource used by components with property bindings (`.:property = …`) to assign DOM properties,
+/// which [`lignin`] can't transport to DOM renderers by itself.
+///
+/// Without this service, property bindings only render as their equivalent attribute, where one exists.
+///
+/// # Implementation Guidelines
+///
+/// `name` is the JavaScript property name exactly as written in the component, e.g. `value` or `readOnly`.
+///
+/// Properties are assigned when their element is added to the DOM and then each time their component renders,
+/// so that e.g. a controlled `<input>`'s `value` is reasserted even if it didn't change.
+pub trait DomProperties {
+	/// Assigns `value` to `element`'s property `name`.
+	fn set_property(&self, element: &HtmlElement, name: &str, value: Property<'_>);
+}
+derive_dependency!(dyn DomProperties);
+
+impl<F: Fn(&HtmlElement, &str, Property<'_>)> DomProperties for F {
+	fn set_property(&self, element: &HtmlElement, name: &str, value: Property<'_>) {
+		self(element, name, value)
+	}
+}
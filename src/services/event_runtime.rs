@@ -0,0 +1,27 @@
+use crate::include::EventFuture;
+use rhizome::sync::derive_dependency;
+
+/// A resource used by components with `async fn` event handlers to schedule [`EventFuture`]s.
+///
+/// # Implementation Guidelines
+///
+/// As with [`ContentRuntime`](`super::ContentRuntime`), the scheduling specifics are up to the implementation.
+///
+/// When running in a browser, I suggest polling the [`EventFuture`] once synchronously at the beginning,
+/// so that any synchronous prefix of the handler runs while the event is still being dispatched.
+///
+/// [`EventFuture`]s request a re-render of their component through its [`Invalidator`](`super::Invalidator`) (if available) once they complete,
+/// so there is no need to do so here.
+pub trait EventRuntime {
+	/// Schedules an [`EventFuture`] to be evaluated to completion.
+	///
+	/// **The scheduling specifics depend entirely on the [`EventRuntime`] implementation.**
+	fn start_event_future(&self, event_future: EventFuture);
+}
+derive_dependency!(dyn EventRuntime);
+
+impl<F: Fn(EventFuture)> EventRuntime for F {
+	fn start_event_future(&self, event_future: EventFuture) {
+		self(event_future)
+	}
+}
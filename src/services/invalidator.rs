@@ -48,7 +48,27 @@ impl dyn Invalidator {
 	/// This API follows Rust `async` semantics and as such is lazy:
 	/// **A re-render will not be requested until the resulting [`NextFrame`] is polled.
 	///
-	/// <!-- TODO: This should have an example showing an asynchronous event handler workflow, once that is available. -->
+	/// # Example
+	///
+	/// In an `async fn` event handler (which requires an [`EventRuntime`](`super::EventRuntime`)):
+	///
+	/// ```rust
+	/// use asteracea::services::Invalidator;
+	///
+	/// asteracea::component! {
+	///   Saving(
+	///     priv dyn invalidator: dyn Invalidator,
+	///   )()
+	///
+	///   <button
+	///     on bubble click = async fn save(self, _) {
+	///       // Let the user see that the click registered…
+	///       self.invalidator.next_frame().await;
+	///       // …before doing more expensive work here.
+	///     }
+	///   >
+	/// }
+	/// ```
 	pub fn next_frame(&self) -> NextFrame<'_> {
 		NextFrame(Some(self))
 	}
@@ -4,9 +4,15 @@ use rhizome::sync::{RefExtract, RefExtracted};
 use std::any::TypeId;
 
 mod content_runtime;
+mod dom_events;
+mod dom_properties;
+mod event_runtime;
 mod invalidator;
 
 pub use content_runtime::ContentRuntime;
+pub use dom_events::{DomEvents, EventModifiers, KeyboardState};
+pub use dom_properties::DomProperties;
+pub use event_runtime::EventRuntime;
 pub use invalidator::{Invalidator, NextFrame};
 
 /// The type of (most) owned handles that are injected when using a trait as dependency key.
@@ -0,0 +1,115 @@
+use asteracea::{
+	include::{EventFuture, EventTasks},
+	services::{EventRuntime, Invalidator},
+};
+use rhizome::sync::{Inject, Node};
+use std::{
+	any::TypeId,
+	cell::{Cell, RefCell},
+	future::Future,
+	pin::Pin,
+	rc::Rc,
+	sync::atomic::{AtomicUsize, Ordering},
+	task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use this_is_fine::FineExt;
+
+asteracea::component! {
+	Saving(
+		priv dyn invalidator: dyn Invalidator,
+	)()
+
+	<button
+		on bubble click = async fn save(self, _) {
+			self.invalidator.next_frame().await;
+		}
+	>
+}
+
+thread_local! {
+	static FUTURES: RefCell<Vec<EventFuture>> = RefCell::default();
+}
+static INVALIDATED: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn event_tasks() {
+	let root = Node::new(TypeId::of::<()>());
+	<dyn EventRuntime>::inject(root.as_ref(), |event_future| {
+		FUTURES.with(|futures| futures.borrow_mut().push(event_future))
+	})
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+	<dyn Invalidator>::inject(root.as_ref(), |_: Option<&mut Context<'_>>| {
+		INVALIDATED.fetch_add(1, Ordering::SeqCst);
+	})
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+
+	let saving = Box::pin(Saving::new(root.as_ref(), Saving::new_args_builder().build()).unwrap());
+
+	let tasks = EventTasks::new(root.as_ref()).unwrap();
+	unsafe { tasks.spawn(async { saving.invalidator.next_frame().await }) }
+	let mut future = FUTURES.with(|futures| futures.borrow_mut().pop().unwrap());
+
+	let waker = fake_waker();
+	let mut cx = Context::from_waker(&waker);
+	assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+	assert_eq!(INVALIDATED.load(Ordering::SeqCst), 1);
+	assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(()));
+	assert_eq!(INVALIDATED.load(Ordering::SeqCst), 2);
+
+	// Cancelled along with `tasks`:
+	unsafe { tasks.spawn(async { saving.invalidator.next_frame().await }) }
+	let mut future = FUTURES.with(|futures| futures.borrow_mut().pop().unwrap());
+	drop(tasks);
+	assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(()));
+	assert_eq!(INVALIDATED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn dropped_while_polling() {
+	struct SetOnDrop(Rc<Cell<bool>>);
+	impl Drop for SetOnDrop {
+		fn drop(&mut self) {
+			self.0.set(true)
+		}
+	}
+
+	let root = Node::new(TypeId::of::<()>());
+	<dyn EventRuntime>::inject(root.as_ref(), |event_future| {
+		FUTURES.with(|futures| futures.borrow_mut().push(event_future))
+	})
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+
+	let tasks = Rc::new(RefCell::new(Some(EventTasks::new(root.as_ref()).unwrap())));
+	let dropped = Rc::new(Cell::new(false));
+	let future = {
+		let tasks = Rc::clone(&tasks);
+		let set_on_drop = SetOnDrop(Rc::clone(&dropped));
+		async move {
+			let _set_on_drop = set_on_drop;
+			// Drops the `EventTasks` (and with them the component, usually) while this task is being polled.
+			drop(tasks.borrow_mut().take());
+			std::future::pending::<()>().await
+		}
+	};
+	unsafe { tasks.borrow().as_ref().unwrap().spawn(future) }
+	let mut future = FUTURES.with(|futures| futures.borrow_mut().pop().unwrap());
+
+	let waker = fake_waker();
+	let mut cx = Context::from_waker(&waker);
+	assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(()));
+	assert!(tasks.borrow().is_none());
+	assert!(dropped.get());
+}
+
+fn fake_waker() -> Waker {
+	const V_TABLE: RawWakerVTable =
+		RawWakerVTable::new(|_| panic!(), |_| panic!(), |_| panic!(), |_| ());
+
+	unsafe { Waker::from_raw(RawWaker::new(&() as *const _, &V_TABLE)) }
+}
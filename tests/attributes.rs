@@ -45,3 +45,195 @@ asteracea::component! {
 	"#"
   >
 }
+
+component! {
+	Toggled()(
+		active: bool,
+		color: Option<&'bump str>,
+	) -> Sync
+
+	<div
+		.id = "toggled"
+		.class+ = "item"
+		.class+ = "active" if {active}
+		.style.color? = {color}
+		.style.font_weight = "bold"
+	>
+}
+
+#[test]
+fn merged_attributes() {
+	use bumpalo::Bump;
+	use std::any::TypeId;
+
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let toggled =
+		Box::pin(Toggled::new(root.as_ref(), Toggled::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+
+	let render = |active, color| {
+		let vdom = toggled
+			.as_ref()
+			.render(
+				&bump,
+				Toggled::render_args_builder()
+					.active(active)
+					.color(color)
+					.build(),
+			)
+			.unwrap();
+		let mut html = String::new();
+		lignin_html::render_fragment(&vdom, &mut html, 1).unwrap();
+		html
+	};
+
+	assert_eq!(
+		render(false, None),
+		r#"<div id="toggled" class="item" style="font-weight: bold"></div>"#
+	);
+	assert_eq!(
+		render(true, Some("red")),
+		r#"<div id="toggled" class="item active" style="color: red; font-weight: bold"></div>"#
+	);
+}
+
+component! {
+	Forwarding()(
+		extra: &'bump [(&'bump str, &'bump str)],
+	) -> Sync
+
+	<div
+		.id = "forwarding"
+		..attrs = {extra.iter().copied()}
+	>
+}
+
+#[test]
+fn spread_attributes() {
+	use bumpalo::Bump;
+	use std::any::TypeId;
+
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let forwarding =
+		Box::pin(Forwarding::new(root.as_ref(), Forwarding::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+
+	let vdom = forwarding
+		.as_ref()
+		.render(
+			&bump,
+			Forwarding::render_args_builder()
+				.extra(&[("data-x", "1"), ("aria-label", "Forwarded")])
+				.build(),
+		)
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 1).unwrap();
+	assert_eq!(
+		html,
+		r#"<div id="forwarding" data-x="1" aria-label="Forwarded"></div>"#
+	);
+}
+
+component! {
+	Controlled()(
+		value: &'bump str,
+		checked: bool,
+	)
+
+	<input
+		.:value = {value}
+		.:checked = {checked}
+		.:readOnly = {true}
+		.:indeterminate = {true}
+		.:innerHTML = "<b>Not an attribute.</b>"
+	>
+}
+
+#[test]
+fn properties() {
+	use bumpalo::Bump;
+	use std::any::TypeId;
+
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let controlled =
+		Box::pin(Controlled::new(root.as_ref(), Controlled::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+
+	let render = |checked| {
+		let vdom = controlled
+			.as_ref()
+			.render(
+				&bump,
+				Controlled::render_args_builder()
+					.value("text")
+					.checked(checked)
+					.build(),
+			)
+			.unwrap();
+		let mut html = String::new();
+		lignin_html::render_fragment(&vdom, &mut html, 1).unwrap();
+		html
+	};
+
+	let unchecked = render(false);
+	assert!(unchecked.starts_with(r#"<input value="text""#));
+	assert!(!unchecked.contains("checked"));
+
+	let checked = render(true);
+	assert!(checked.starts_with(r#"<input value="text" checked"#));
+	assert!(checked.contains("readonly"));
+	assert!(!checked.contains("indeterminate"));
+	assert!(!checked.to_lowercase().contains("innerhtml"));
+	assert!(!checked.contains("Not an attribute."));
+}
+
+#[test]
+fn properties_are_assigned_only_in_the_dom() {
+	use asteracea::{services::DomProperties, Property};
+	use bumpalo::Bump;
+	use lignin::web::HtmlElement;
+	use rhizome::sync::Inject;
+	use std::{
+		any::TypeId,
+		sync::atomic::{AtomicUsize, Ordering},
+	};
+	use this_is_fine::FineExt;
+
+	static ASSIGNED: AtomicUsize = AtomicUsize::new(0);
+
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	<dyn DomProperties>::inject(
+		root.as_ref(),
+		|_: &HtmlElement, _: &str, _: Property<'_>| {
+			ASSIGNED.fetch_add(1, Ordering::SeqCst);
+		},
+	)
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+	let controlled =
+		Box::pin(Controlled::new(root.as_ref(), Controlled::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = controlled
+		.as_ref()
+		.render(
+			&bump,
+			Controlled::render_args_builder()
+				.value("text")
+				.checked(true)
+				.build(),
+		)
+		.unwrap();
+	assert!(matches!(
+		vdom,
+		lignin::Node::HtmlElement {
+			dom_binding: Some(_),
+			..
+		}
+	));
+
+	// The element was never added to a DOM.
+	assert_eq!(ASSIGNED.load(Ordering::SeqCst), 0);
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, pin::Pin};
+use std::{fmt::Debug, marker::PhantomData, pin::Pin};
 
 asteracea::component! {
 	pub Boxed<T>()() -> Sync
@@ -46,6 +46,39 @@ asteracea::component! {
 	box <*Whered::<T>>
 }
 
+struct Capacity<const N: usize> {}
+
+asteracea::component! {
+	pub Buffered<const N: usize>()() -> Sync
+
+	[
+		box []
+		box priv named: struct Buffer::<const N: usize> []
+		box priv predefined: Capacity::<N> []
+	]
+}
+
+struct Iterating<T, I: ?Sized> {
+	boxed: Boxed<T>,
+	_iterator: PhantomData<Box<I>>,
+}
+
+impl<T, I: ?Sized> Iterating<T, I> {
+	fn boxed_pinned(self: Pin<&Self>) -> Pin<&Boxed<T>> {
+		unsafe { self.map_unchecked(|p| &p.boxed) }
+	}
+}
+
+asteracea::component! {
+	// Parameters in a different order than the arguments below.
+	pub Iterated<U, T>()() -> Sync
+
+	box priv iterating: Iterating::<T, dyn Iterator<Item = U> + Send + Sync> [
+		let self._iterator: PhantomData<Box<dyn Iterator<Item = U> + Send + Sync>> = PhantomData;
+		<*Boxed::<T> priv boxed>
+	]
+}
+
 asteracea::component! {
 	#[allow(dead_code)] // Used below; Waiting on min_specialization.
 	Picky<T: Debug>()()
@@ -0,0 +1,60 @@
+use bumpalo::Bump;
+use lignin::Node;
+use std::any::TypeId;
+
+asteracea::component! {
+	Labelled(
+		priv prefix: &'static str = "new default",
+	)(
+		label: &'bump str = "render default",
+	) -> Sync
+
+	!"{}: {}"(self.prefix, label)
+}
+
+asteracea::component! {
+	Forwarding(
+		prefix: Option<&'static str> = None,
+	)(
+		label: Option<&'bump str> = None,
+	) -> Sync
+
+	<*Labelled *prefix? = {prefix} .label? = {label}>
+}
+
+fn render(new_prefix: Option<&'static str>, label: Option<&str>) -> String {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let component = Box::pin(
+		Forwarding::new(
+			root.as_ref(),
+			Forwarding::new_args_builder().prefix(new_prefix).build(),
+		)
+		.unwrap(),
+	);
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(
+			&bump,
+			Forwarding::render_args_builder().label(label).build(),
+		)
+		.unwrap();
+
+	match vdom {
+		Node::Text { text, .. } => text.to_string(),
+		_ => panic!("Expected text."),
+	}
+}
+
+#[test]
+fn defaults() {
+	assert_eq!(render(None, None), "new default: render default");
+}
+
+#[test]
+fn forwarded() {
+	assert_eq!(render(Some("new"), Some("render")), "new: render");
+	assert_eq!(render(Some("new"), None), "new: render default");
+	assert_eq!(render(None, Some("render")), "new default: render");
+}
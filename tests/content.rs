@@ -1,7 +1,9 @@
 use std::any::TypeId;
 
+use asteracea::include::render_callback::RenderOnce;
 use bumpalo::Bump;
 use ergo_pin::ergo_pin;
+use lignin::ThreadBound;
 use rhizome::sync::Node;
 
 asteracea::component! {
@@ -43,3 +45,204 @@ fn content_in_container() {
 		"<custom-container><custom-content></custom-content></custom-container>"
 	)
 }
+
+asteracea::component! {
+	Twice()(...)
+
+	<"custom-twice"
+		<"custom-first" ...>
+		<"custom-second" ...>
+	>
+}
+
+asteracea::component! {
+	TwiceParent()() -> Sync
+
+	<*Twice
+		<*Content>
+	>
+}
+
+#[test]
+#[ergo_pin]
+fn multi_use_content() {
+	let root = Node::new(TypeId::of::<()>());
+	let parent =
+		pin!(TwiceParent::new(root.as_ref(), TwiceParent::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+	let vdom = parent
+		.as_ref()
+		.render(&bump, TwiceParent::render_args_builder().build())
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 10).unwrap();
+	assert_eq!(
+		html,
+		"<custom-twice><custom-first><custom-content></custom-content></custom-first><custom-second><custom-content></custom-content></custom-second></custom-twice>"
+	)
+}
+
+asteracea::component! {
+	Framed()(
+		class?: &'bump str,
+		..
+	)
+
+	<"custom-frame"
+		."class"? = {class}
+		..
+	>
+}
+
+asteracea::component! {
+	FramedParent()(
+		class?: &'bump str,
+	) -> Sync
+
+	<*Framed .class? = {class}
+		<*Content>
+	>
+}
+
+#[test]
+#[ergo_pin]
+fn content_with_optional_parameter() {
+	let root = Node::new(TypeId::of::<()>());
+	let parent =
+		pin!(FramedParent::new(root.as_ref(), FramedParent::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+
+	let vdom = parent
+		.as_ref()
+		.render(&bump, FramedParent::render_args_builder().build())
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 3).unwrap();
+	assert_eq!(
+		html,
+		"<custom-frame><custom-content></custom-content></custom-frame>"
+	);
+
+	let vdom = parent
+		.as_ref()
+		.render(
+			&bump,
+			FramedParent::render_args_builder().class("framed").build(),
+		)
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 3).unwrap();
+	assert_eq!(
+		html,
+		"<custom-frame class=\"framed\"><custom-content></custom-content></custom-frame>"
+	);
+}
+
+asteracea::component! {
+	Captioned()(
+		class?: &'bump str,
+		caption: (
+			asteracea::__::AnonymousContentParentParameters,
+			Box<RenderOnce<'_, 'bump, ThreadBound>>,
+		),
+	)
+
+	<"custom-captioned"
+		."class"? = {class}
+		{(caption.1)(bump)?}
+	>
+}
+
+asteracea::component! {
+	CaptionedParent()(
+		class?: &'bump str,
+	)
+
+	<*Captioned .class? = {class}
+		'caption: <button
+			on bubble click = fn clicked(self, _) {}
+		>
+	>
+}
+
+#[test]
+#[ergo_pin]
+fn named_slot_with_optional_parameter_and_handler() {
+	let root = Node::new(TypeId::of::<()>());
+	let parent = pin!(CaptionedParent::new(
+		root.as_ref(),
+		CaptionedParent::new_args_builder().build()
+	)
+	.unwrap());
+	let bump = Bump::new();
+
+	let vdom = parent
+		.as_ref()
+		.render(&bump, CaptionedParent::render_args_builder().build())
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 3).unwrap();
+	assert_eq!(
+		html,
+		"<custom-captioned><button></button></custom-captioned>"
+	);
+
+	let vdom = parent
+		.as_ref()
+		.render(
+			&bump,
+			CaptionedParent::render_args_builder()
+				.class("captioned")
+				.build(),
+		)
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 3).unwrap();
+	assert_eq!(
+		html,
+		"<custom-captioned class=\"captioned\"><button></button></custom-captioned>"
+	);
+}
+
+asteracea::component! {
+	Counted()(
+		count: usize,
+		item: (
+			asteracea::__::AnonymousContentParentParameters,
+			Box<
+				dyn '_
+					+ FnOnce(&'bump Bump, usize, &str)
+						-> asteracea::error::Result<lignin::Node<'bump, ThreadBound>>,
+			>,
+		),
+	)
+
+	<"custom-counted"
+		{(item.1)(bump, count, "items")?}
+	>
+}
+
+asteracea::component! {
+	CountedParent()()
+
+	<*Counted .count = {3}
+		'item: |count, noun: &str| !"{} {}"(count, noun)
+	>
+}
+
+#[test]
+#[ergo_pin]
+fn slot_arguments() {
+	let root = Node::new(TypeId::of::<()>());
+	let parent =
+		pin!(CountedParent::new(root.as_ref(), CountedParent::new_args_builder().build()).unwrap());
+	let bump = Bump::new();
+
+	let vdom = parent
+		.as_ref()
+		.render(&bump, CountedParent::render_args_builder().build())
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 3).unwrap();
+	assert_eq!(html, "<custom-counted>3 items</custom-counted>");
+}
@@ -0,0 +1,35 @@
+use bumpalo::Bump;
+use std::any::TypeId;
+
+mod framework {
+	pub use asteracea;
+}
+
+framework::asteracea::component! {
+	#![asteracea(crate = crate::framework::asteracea)]
+
+	Reexported()(
+		class?: &'bump str,
+	) -> Sync
+
+	<div ."class"? = {class}
+		!"{}"("reexported")
+	>
+}
+
+#[test]
+fn reexported() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let component =
+		Box::pin(Reexported::new(root.as_ref(), Reexported::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Reexported::render_args_builder().build())
+		.unwrap();
+
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 2).unwrap();
+	assert_eq!(html, "<div>reexported</div>");
+}
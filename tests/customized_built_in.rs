@@ -0,0 +1,84 @@
+use bumpalo::Bump;
+use lignin::Node;
+use rhizome::sync::Node as RhizomeNode;
+use std::any::TypeId;
+
+asteracea::component! {
+	FancyButton()() -> Sync
+
+	<button is = "fancy-button"
+		."type" = "button"
+		"Fancy!"
+	>
+}
+
+asteracea::component! {
+	DynamicallyFancy()(
+		is: &'bump str,
+	) -> Sync
+
+	<button is = {is}>
+}
+
+#[test]
+fn literal() {
+	let root = RhizomeNode::new(TypeId::of::<()>());
+	let component =
+		Box::pin(FancyButton::new(root.as_ref(), FancyButton::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	match component
+		.as_ref()
+		.render(&bump, FancyButton::render_args_builder().build())
+		.unwrap()
+	{
+		Node::HtmlElement { element, .. } => {
+			assert_eq!(element.creation_options.is(), Some("fancy-button"))
+		}
+		_ => panic!("Expected an HTML element."),
+	}
+}
+
+#[test]
+fn dynamic() {
+	let root = RhizomeNode::new(TypeId::of::<()>());
+	let component = Box::pin(
+		DynamicallyFancy::new(root.as_ref(), DynamicallyFancy::new_args_builder().build()).unwrap(),
+	);
+
+	let bump = Bump::new();
+	match component
+		.as_ref()
+		.render(
+			&bump,
+			DynamicallyFancy::render_args_builder()
+				.is("plain-button")
+				.build(),
+		)
+		.unwrap()
+	{
+		Node::HtmlElement { element, .. } => {
+			assert_eq!(element.creation_options.is(), Some("plain-button"))
+		}
+		_ => panic!("Expected an HTML element."),
+	}
+}
+
+#[test]
+fn html() {
+	let root = RhizomeNode::new(TypeId::of::<()>());
+	let component =
+		Box::pin(FancyButton::new(root.as_ref(), FancyButton::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, FancyButton::render_args_builder().build())
+		.unwrap();
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 100).unwrap();
+	assert_eq!(
+		html,
+		r#"<button is="fancy-button" type="button">Fancy!</button>"#
+	)
+}
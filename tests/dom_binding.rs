@@ -0,0 +1,185 @@
+use asteracea::{
+	include::{DomBinding, PropertyTarget},
+	services::{DomProperties, ServiceHandle},
+	Property,
+};
+use bumpalo::Bump;
+use lignin::{DomRef, Node};
+use rhizome::sync::{Extract, Inject, Node as RhizomeNode};
+use std::{
+	any::TypeId,
+	pin::Pin,
+	sync::{Arc, Mutex},
+};
+
+asteracea::component! {
+	pub Bound()()
+
+	<div ref pub self.div
+		"Text" ref pub self.text
+		!"Formatted text" ref self.formatted
+		<!-- "Comment" ref self.comment -->
+		<svg ref self.svg>
+	>
+}
+
+#[test]
+fn dom_bindings() {
+	let root = RhizomeNode::new(TypeId::of::<()>());
+	let component = Box::pin(Bound::new(root.as_ref(), Bound::new_args_builder().build()).unwrap());
+
+	assert!(component.as_ref().div_pinned().get().is_none());
+	assert!(component.as_ref().text_pinned().get().is_none());
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Bound::render_args_builder().build())
+		.unwrap();
+	let element = match vdom {
+		Node::HtmlElement {
+			element,
+			dom_binding: Some(_),
+		} => element,
+		_ => panic!("Expected a bound HTML element."),
+	};
+	match element.content {
+		Node::Multi(
+			[Node::Text {
+				dom_binding: Some(_),
+				..
+			}, Node::Text {
+				dom_binding: Some(_),
+				..
+			}, Node::Comment {
+				dom_binding: Some(_),
+				..
+			}, Node::SvgElement {
+				dom_binding: Some(_),
+				..
+			}],
+		) => (),
+		_ => panic!("Expected bound children."),
+	}
+}
+
+#[test]
+fn binding_callback() {
+	let binding = Box::pin(DomBinding::<String>::new());
+	let callback = binding.as_ref().__Asteracea__registration().to_ref();
+	assert_eq!(binding.as_ref().get(), None);
+
+	let node = "node".to_string();
+	callback.call(DomRef::Added(&node));
+	assert_eq!(binding.as_ref().get(), Some(node.clone()));
+
+	callback.call(DomRef::Removing(&node));
+	assert_eq!(binding.as_ref().get(), None);
+}
+
+#[derive(Clone)]
+struct FakeElement {
+	log: Arc<Mutex<Vec<String>>>,
+}
+
+impl PropertyTarget for FakeElement {
+	fn set_property(&self, _: &dyn DomProperties, name: &str, value: Property<'_>) {
+		self.log
+			.lock()
+			.unwrap()
+			.push(format!("{} = {:?}", name, value));
+	}
+}
+
+fn fake_dom() -> ServiceHandle<dyn DomProperties> {
+	use lignin::web::HtmlElement;
+	use this_is_fine::FineExt;
+
+	let root = RhizomeNode::new(TypeId::of::<()>());
+	<dyn DomProperties>::inject(
+		root.as_ref(),
+		|_: &HtmlElement, _: &str, _: Property<'_>| (),
+	)
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+	<dyn DomProperties as Extract>::extract(root.as_ref())
+		.unwrap()
+		.unwrap()
+}
+
+#[test]
+fn property_assignment() {
+	let dom = fake_dom();
+
+	let binding = Box::pin(DomBinding::<FakeElement>::new());
+	let callback = binding.as_ref().__Asteracea__registration().to_ref();
+	let log = Arc::new(Mutex::new(Vec::new()));
+	let element = FakeElement { log: log.clone() };
+
+	// Not present yet, so nothing is assigned.
+	binding
+		.as_ref()
+		.__Asteracea__assign_properties(Some(&dom), &[("value", Property::Str("a"))]);
+	assert!(log.lock().unwrap().is_empty());
+
+	callback.call(DomRef::Added(&element));
+	assert_eq!(*log.lock().unwrap(), [r#"value = Str("a")"#]);
+
+	// Present, so the new value is assigned right away.
+	binding.as_ref().__Asteracea__assign_properties(
+		Some(&dom),
+		&[
+			("value", Property::Str("b")),
+			("checked", Property::Bool(true)),
+		],
+	);
+	assert_eq!(
+		*log.lock().unwrap(),
+		[
+			r#"value = Str("a")"#,
+			r#"value = Str("b")"#,
+			"checked = Bool(true)"
+		]
+	);
+
+	callback.call(DomRef::Removing(&element));
+	binding
+		.as_ref()
+		.__Asteracea__assign_properties(Some(&dom), &[("value", Property::Str("c"))]);
+	assert_eq!(log.lock().unwrap().len(), 3);
+}
+
+#[derive(Clone)]
+struct ReentrantElement {
+	binding: Pin<&'static DomBinding<ReentrantElement>>,
+	present: Arc<Mutex<Vec<bool>>>,
+}
+
+impl PropertyTarget for ReentrantElement {
+	fn set_property(&self, _: &dyn DomProperties, _: &str, _: Property<'_>) {
+		let present = self.binding.get().is_some();
+		self.present.lock().unwrap().push(present);
+	}
+}
+
+#[test]
+fn reentrant_property_assignment() {
+	let dom = fake_dom();
+
+	let binding = unsafe {
+		// Leaked, so it's never moved.
+		Pin::new_unchecked(&*Box::leak(Box::new(DomBinding::<ReentrantElement>::new())))
+	};
+	let callback = binding.__Asteracea__registration().to_ref();
+	let present = Arc::new(Mutex::new(Vec::new()));
+	let element = ReentrantElement {
+		binding,
+		present: present.clone(),
+	};
+
+	binding.__Asteracea__assign_properties(Some(&dom), &[("value", Property::Str("a"))]);
+	callback.call(DomRef::Added(&element));
+	binding.__Asteracea__assign_properties(Some(&dom), &[("value", Property::Str("b"))]);
+	assert_eq!(*present.lock().unwrap(), [true, true]);
+}
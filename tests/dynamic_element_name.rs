@@ -0,0 +1,52 @@
+use asteracea::error::{Escalation, InvalidElementName};
+use bumpalo::Bump;
+use rhizome::sync::Node;
+use std::{any::TypeId, panic::AssertUnwindSafe};
+
+asteracea::component! {
+	Dynamic()(
+		name: &'bump str,
+	) -> Sync
+
+	<{name}
+		.class = "dynamic"
+		"Content"
+	>
+}
+
+#[test]
+fn valid() {
+	let root = Node::new(TypeId::of::<()>());
+	let component =
+		Box::pin(Dynamic::new(root.as_ref(), Dynamic::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Dynamic::render_args_builder().name("h2").build())
+		.unwrap();
+	match vdom {
+		lignin::Node::HtmlElement { element, .. } => assert_eq!(element.name, "h2"),
+		_ => panic!("Expected an HTML element."),
+	}
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn invalid() {
+	for name in ["", "h1 onclick", "h1>", "a\"b"] {
+		let root = Node::new(TypeId::of::<()>());
+		let component =
+			Box::pin(Dynamic::new(root.as_ref(), Dynamic::new_args_builder().build()).unwrap());
+
+		let bump = Bump::new();
+		let caught = Escalation::catch::<_, _, InvalidElementName>(AssertUnwindSafe(|| {
+			component
+				.as_ref()
+				.render(&bump, Dynamic::render_args_builder().name(name).build())
+				.map(|_| ())
+		}))
+		.unwrap_err();
+		assert_eq!(caught.into_inner().name(), name);
+	}
+}
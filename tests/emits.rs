@@ -0,0 +1,119 @@
+use asteracea::include::Emitter;
+use bumpalo::Bump;
+use std::{any::TypeId, marker::PhantomData, pin::Pin, sync::Mutex};
+
+#[derive(Clone, Copy)]
+pub struct ItemId(pub usize);
+
+asteracea::component! {
+	pub List()(
+		items: &'bump [usize],
+	) -> Sync?
+	emits
+		select: ItemId,
+		clear: (),
+
+	<ul
+		for item: usize in items.iter().copied() {[
+			<li
+				on bubble click = with {(select, item)}: (Option<Emitter<ItemId>>, usize) fn clicked(self, _, (select, item)) {
+					if let Some(select) = select {
+						select.emit(ItemId(item))
+					}
+				}
+			>
+		]}
+		<button
+			on bubble click = with {clear}: Option<Emitter<()>> fn cleared(self, _, clear) {
+				if let Some(clear) = clear {
+					clear.emit(())
+				}
+			}
+		>
+	>
+}
+
+asteracea::component! {
+	pub Picker()() -> Sync?
+
+	<*List
+		on select = Self::on_select
+		on clear = cleared
+		.items = {&[1, 2, 3]}
+	>
+}
+
+impl Picker {
+	fn on_select(&self, _: ItemId) {}
+}
+
+fn cleared(_: Pin<&Picker>, _: ()) {}
+
+#[test]
+fn emitted_events() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let picker = Box::pin(Picker::new(root.as_ref(), Picker::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = picker
+		.as_ref()
+		.render(&bump, Picker::render_args_builder().build())
+		.unwrap();
+
+	let mut html = String::new();
+	lignin_html::render_fragment(&vdom, &mut html, 100).unwrap();
+	assert_eq!(
+		html,
+		"<ul><li></li><li></li><li></li><button></button></ul>"
+	);
+}
+
+asteracea::component! {
+	pub Echo<T: 'static + Copy>(
+		priv _phantom: PhantomData<T> = PhantomData,
+	)(
+		value: T,
+	)
+	emits
+		echoed: T,
+
+	with {
+		if let Some(echoed) = echoed {
+			echoed.emit(value);
+		}
+	} <span>
+}
+
+asteracea::component! {
+	pub Echoing(
+		priv received: Mutex<Vec<usize>> = Mutex::default(),
+	)()
+
+	[
+		<*Echo::<usize> on echoed = Self::on_echoed .value = {1}>
+		<*Echo::<usize> on echoed = Self::on_echoed .value = {2}>
+	]
+}
+
+impl Echoing {
+	fn on_echoed(&self, value: usize) {
+		self.received.lock().unwrap().push(value)
+	}
+}
+
+#[test]
+fn generic_emitted_events_arrive() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let echoing =
+		Box::pin(Echoing::new(root.as_ref(), Echoing::new_args_builder().build()).unwrap());
+
+	for _ in 0..2 {
+		let bump = Bump::new();
+		echoing
+			.as_ref()
+			.render(&bump, Echoing::render_args_builder().build())
+			.unwrap();
+	}
+
+	assert_eq!(*echoing.received.lock().unwrap(), [1, 2, 1, 2]);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "error-abort")]
+
+use asteracea::error::EscalateResult;
+use rhizome::sync::Node;
+use std::{
+	any::TypeId,
+	env,
+	error::Error,
+	fmt::{self, Display, Formatter},
+	process::Command,
+};
+
+/// Set for the child process spawned by [`escalation_aborts`].
+const CHILD: &str = "ASTERACEA_TEST_ERROR_ABORT_CHILD";
+
+#[derive(Debug)]
+struct AnError;
+impl Display for AnError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "A test error was raised")
+	}
+}
+impl Error for AnError {}
+
+asteracea::component! {
+	FailingNew()()
+
+	new with { Err::<(), _>(AnError).escalate()?; }
+
+	[]
+}
+
+asteracea::component! {
+	Outer()()
+
+	<*FailingNew>
+}
+
+/// Only escalates in the child process, as that aborts it.
+#[test]
+fn child() {
+	if env::var_os(CHILD).is_some() {
+		let root = Node::new(TypeId::of::<()>());
+		let _ = Outer::new(root.as_ref(), Outer::new_args_builder().build());
+		unreachable!("Escalating should have aborted the process.");
+	}
+}
+
+#[test]
+fn escalation_aborts() {
+	let output = Command::new(env::current_exe().unwrap())
+		.args(["child", "--exact", "--nocapture", "--test-threads=1"])
+		.env(CHILD, "1")
+		.output()
+		.unwrap();
+
+	assert!(!output.status.success());
+	#[cfg(unix)]
+	{
+		use std::os::unix::process::ExitStatusExt;
+		assert_eq!(output.status.signal(), Some(6)); // SIGABRT
+	}
+
+	let stderr = String::from_utf8(output.stderr).unwrap();
+	assert!(
+		stderr.contains("GUI error escalation (aborting):\nA test error was raised\n"),
+		"Unexpected stderr: {}",
+		stderr
+	);
+	// The process aborts where the error is escalated, so no trace frames are added.
+	assert!(!stderr.contains("\nfrom "), "Unexpected stderr: {}", stderr);
+}
@@ -0,0 +1,138 @@
+use asteracea::{
+	components::{ErrorBoundary, ResetPolicy},
+	error::EscalateResult,
+};
+use bumpalo::Bump;
+use lignin_html::render_fragment;
+use rhizome::sync::Node;
+use std::{
+	any::TypeId,
+	error::Error,
+	fmt::{self, Display, Formatter},
+	panic::{catch_unwind, AssertUnwindSafe},
+	pin::Pin,
+};
+
+#[derive(Debug)]
+struct AnError;
+impl Display for AnError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "A test error was raised")
+	}
+}
+impl Error for AnError {}
+
+asteracea::component! {
+	Failing()
+	#[allow(unreachable_code)] () -> Sync
+
+	{ return Err(AnError).escalate(); }
+}
+
+asteracea::component! {
+	Panicking()
+	#[allow(unreachable_code)] () -> Sync
+
+	{ panic!("Panicking on purpose.") }
+}
+
+asteracea::component! {
+	Guarded(
+		reset_policy: ResetPolicy = ResetPolicy::Retry,
+	)(
+		fail: bool = false,
+		panic: bool = false,
+	) -> Sync
+
+	<*ErrorBoundary priv boundary *reset_policy = {reset_policy}
+		'content: [
+			if {fail} <*Failing>
+			if {panic} <*Panicking>
+			"Fine"
+		]
+		'fallback: |caught| !"Failed with {} trace frame(s)"(caught.trace().len())
+	>
+}
+
+fn render(guarded: Pin<&Guarded>, fail: bool, panic: bool) -> String {
+	let bump = Bump::new();
+	let vdom = guarded
+		.render(
+			&bump,
+			Guarded::render_args_builder()
+				.fail(fail)
+				.panic(panic)
+				.build(),
+		)
+		.unwrap();
+
+	let mut html = String::new();
+	render_fragment(&vdom, &mut html, 1).unwrap();
+	html
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn retry() {
+	let root = Node::new(TypeId::of::<()>());
+	let guarded =
+		Box::pin(Guarded::new(root.as_ref(), Guarded::new_args_builder().build()).unwrap());
+
+	assert_eq!(render(guarded.as_ref(), false, false), "Fine");
+	assert!(guarded.boundary.with_caught(|caught| caught.is_none()));
+
+	assert_eq!(
+		render(guarded.as_ref(), true, false),
+		"Failed with 1 trace frame(s)"
+	);
+	guarded.boundary.with_caught(|caught| {
+		let caught = caught.unwrap();
+		assert!(!caught.was_panic());
+		assert_eq!(caught.trace()[0].component(), "Guarded");
+	});
+
+	assert_eq!(render(guarded.as_ref(), false, false), "Fine");
+	assert!(guarded.boundary.with_caught(|caught| caught.is_none()));
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn manual_reset() {
+	let root = Node::new(TypeId::of::<()>());
+	let guarded = Box::pin(
+		Guarded::new(
+			root.as_ref(),
+			Guarded::new_args_builder()
+				.reset_policy(ResetPolicy::Manual)
+				.build(),
+		)
+		.unwrap(),
+	);
+
+	assert_eq!(
+		render(guarded.as_ref(), true, false),
+		"Failed with 1 trace frame(s)"
+	);
+	assert_eq!(
+		render(guarded.as_ref(), false, false),
+		"Failed with 1 trace frame(s)"
+	);
+
+	guarded.boundary.reset();
+	assert!(guarded.boundary.with_caught(|caught| caught.is_none()));
+	assert_eq!(render(guarded.as_ref(), false, false), "Fine");
+}
+
+#[test]
+#[cfg_attr(
+	any(feature = "error-abort", target_arch = "wasm32"),
+	ignore = "Panics can't be caught."
+)]
+fn panics_propagate() {
+	let root = Node::new(TypeId::of::<()>());
+	let guarded =
+		Box::pin(Guarded::new(root.as_ref(), Guarded::new_args_builder().build()).unwrap());
+
+	assert!(catch_unwind(AssertUnwindSafe(|| render(guarded.as_ref(), false, true))).is_err());
+	assert!(guarded.boundary.with_caught(|caught| caught.is_none()));
+}
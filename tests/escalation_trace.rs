@@ -0,0 +1,226 @@
+use asteracea::error::{Escalate, EscalateResult, Escalation, Phase, TraceFrame};
+use bumpalo::Bump;
+use rhizome::sync::Node;
+use std::{
+	any::TypeId,
+	error::Error,
+	fmt::{self, Display, Formatter},
+	panic::AssertUnwindSafe,
+};
+
+#[derive(Debug)]
+struct AnError;
+impl Display for AnError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "A test error was raised")
+	}
+}
+impl Error for AnError {}
+
+asteracea::component! {
+	FailingNew()()
+
+	new with { Err::<(), _>(AnError).escalate()?; }
+
+	[]
+}
+
+asteracea::component! {
+	FailingRender()
+	#[allow(unreachable_code)] ()
+
+	{ return Err(AnError).escalate(); }
+}
+
+const CONTAINING_LINE: u32 = line!();
+asteracea::component! {
+	Containing()(
+		fail_new: bool = false,
+	)
+
+	dyn if {fail_new} <*FailingNew>
+	else <*FailingRender>
+}
+const FAILING_NEW_LINE: u32 = CONTAINING_LINE + 6;
+const FAILING_RENDER_LINE: u32 = CONTAINING_LINE + 7;
+
+const OUTER_LINE: u32 = line!();
+asteracea::component! {
+	Outer()(
+		fail_new: bool = false,
+	)
+
+	<*Containing .fail_new = {fail_new}>
+}
+const CONTAINING_ELEMENT_LINE: u32 = OUTER_LINE + 6;
+
+fn frames(fail_new: bool) -> Vec<TraceFrame> {
+	let root = Node::new(TypeId::of::<()>());
+	let outer = Box::pin(Outer::new(root.as_ref(), Outer::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let caught = Escalation::catch_any(AssertUnwindSafe(|| {
+		outer
+			.as_ref()
+			.render(
+				&bump,
+				Outer::render_args_builder().fail_new(fail_new).build(),
+			)
+			.map(|_| ())
+	}))
+	.unwrap_err();
+	caught.trace().to_vec()
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn render_trace() {
+	let frames = frames(false);
+	assert_eq!(frames.len(), 2);
+
+	assert_eq!(frames[0].callee(), "FailingRender");
+	assert_eq!(frames[0].phase(), Phase::Render);
+	assert_eq!(frames[0].component(), "Containing");
+	assert_eq!(frames[0].file(), file!());
+	assert_eq!(frames[0].line(), FAILING_RENDER_LINE);
+
+	assert_eq!(frames[1].callee(), "Containing");
+	assert_eq!(frames[1].phase(), Phase::Render);
+	assert_eq!(frames[1].component(), "Outer");
+	assert_eq!(frames[1].line(), CONTAINING_ELEMENT_LINE);
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn new_trace() {
+	let frames = frames(true);
+	assert_eq!(frames.len(), 2);
+
+	assert_eq!(frames[0].callee(), "FailingNew");
+	assert_eq!(frames[0].phase(), Phase::New);
+	assert_eq!(frames[0].component(), "Containing");
+	assert_eq!(frames[0].line(), FAILING_NEW_LINE);
+
+	assert_eq!(frames[1].callee(), "Containing");
+	assert_eq!(frames[1].phase(), Phase::Render);
+	assert_eq!(frames[1].component(), "Outer");
+}
+
+#[test]
+#[cfg_attr(
+	any(feature = "error-abort", feature = "force-unwind"),
+	ignore = "Escalations aren't returned."
+)]
+fn display() {
+	let root = Node::new(TypeId::of::<()>());
+	let outer = Box::pin(Outer::new(root.as_ref(), Outer::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let escalation = match outer
+		.as_ref()
+		.render(&bump, Outer::render_args_builder().build())
+	{
+		Ok(_) => panic!("Expected an escalation."),
+		Err(escalation) => escalation,
+	};
+	let displayed = escalation.to_string();
+	assert!(displayed.starts_with("A test error was raised\n"));
+	assert!(displayed.contains(&format!(
+		"from FailingRender::render (in Containing) at {}:{}:",
+		file!(),
+		FAILING_RENDER_LINE
+	)));
+	assert!(displayed.contains(&format!(
+		"from Containing::render (in Outer) at {}:{}:",
+		file!(),
+		CONTAINING_ELEMENT_LINE
+	)));
+}
+
+asteracea::component! {
+	Panicking()
+	#[allow(unreachable_code)] ()
+
+	{ panic!("A plain panic") }
+}
+
+asteracea::component! {
+	ContainingPanicking()()
+
+	<*Panicking>
+}
+
+#[test]
+fn plain_panics_keep_their_payload() {
+	let root = Node::new(TypeId::of::<()>());
+	let containing = Box::pin(
+		ContainingPanicking::new(
+			root.as_ref(),
+			ContainingPanicking::new_args_builder().build(),
+		)
+		.unwrap(),
+	);
+
+	let bump = Bump::new();
+	let panic = std::panic::catch_unwind(AssertUnwindSafe(|| {
+		containing
+			.as_ref()
+			.render(&bump, ContainingPanicking::render_args_builder().build())
+			.map(|_| ())
+	}))
+	.unwrap_err();
+	assert_eq!(panic.downcast_ref::<&str>(), Some(&"A plain panic"));
+}
+
+asteracea::component! {
+	Rethrowing()
+	#[allow(unreachable_code)] ()
+
+	{
+		let caught = Escalation::catch_any(|| -> asteracea::error::Result<()> {
+			panic!("A re-escalated panic")
+		})
+		.unwrap_err();
+		return Err(caught.escalate());
+	}
+}
+
+const CONTAINING_RETHROWING_LINE: u32 = line!();
+asteracea::component! {
+	ContainingRethrowing()()
+
+	<*Rethrowing>
+}
+
+#[test]
+#[cfg_attr(
+	any(feature = "error-abort", target_arch = "wasm32"),
+	ignore = "Panics can't be caught."
+)]
+fn unwinding_escalations_are_traced() {
+	let root = Node::new(TypeId::of::<()>());
+	let containing = Box::pin(
+		ContainingRethrowing::new(
+			root.as_ref(),
+			ContainingRethrowing::new_args_builder().build(),
+		)
+		.unwrap(),
+	);
+
+	let bump = Bump::new();
+	let caught = Escalation::catch_any(AssertUnwindSafe(|| {
+		containing
+			.as_ref()
+			.render(&bump, ContainingRethrowing::render_args_builder().build())
+			.map(|_| ())
+	}))
+	.unwrap_err();
+	assert!(caught.was_panic());
+
+	let frames = caught.trace();
+	assert_eq!(frames.len(), 1);
+	assert_eq!(frames[0].callee(), "Rethrowing");
+	assert_eq!(frames[0].phase(), Phase::Render);
+	assert_eq!(frames[0].component(), "ContainingRethrowing");
+	assert_eq!(frames[0].line(), CONTAINING_RETHROWING_LINE + 4);
+}
@@ -9,6 +9,35 @@ asteracea::component! {
 	>
 }
 
+asteracea::component! {
+	pub Validated()() -> Sync?
+
+	// `volumechange` doesn't bubble, so it's bound without mode.
+	<video
+		on volumechange = fn changed(self, _) {}
+	>
+}
+
+#[test]
+fn validated_event() {
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	let component =
+		Box::pin(Validated::new(root.as_ref(), Validated::new_args_builder().build()).unwrap());
+
+	let bump = bumpalo::Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Validated::render_args_builder().build())
+		.unwrap();
+	match vdom {
+		lignin::Node::HtmlElement { element, .. } => {
+			assert_eq!(element.event_bindings.len(), 1);
+			assert_eq!(element.event_bindings[0].name, "volumechange");
+		}
+		_ => panic!("Expected an HTML element."),
+	}
+}
+
 asteracea::component! {
 	pub Mvc()() -> Sync
 
@@ -46,3 +75,259 @@ asteracea::component! {
 fn detached1(_: *const Detached, _: lignin::web::Event) {}
 fn detached2(_: &Detached, _: lignin::web::Event) {}
 fn detached3(_: std::pin::Pin<&Detached>, _: lignin::web::Event) {}
+
+asteracea::component! {
+	pub Modified()() -> Sync
+
+	<form
+		on bubble prevent_default submit = fn on_submit(self, _) {}
+		on bubble stop_propagation click = passive Self::on_click
+		on bubble prevent_default stop_propagation keydown = active once detached_modified
+	>
+}
+
+impl Modified {
+	fn on_click(&self, _: lignin::web::Event) {}
+}
+
+fn detached_modified(_: std::pin::Pin<&Modified>, _: lignin::web::Event) {}
+
+asteracea::component! {
+	pub Rows()(
+		rows: &'bump [usize],
+	) -> Sync
+
+	<ul
+		for row: usize in rows.iter().copied() {[
+			<li
+				<button
+					on bubble click = with {row}: usize fn select(self, _, row) {
+						let _ = row;
+					}
+					on bubble dblclick = with {row}: usize Self::on_dblclick
+				>
+				<button
+					on bubble stop_propagation click = once with {row * 2}: usize detached_row
+				>
+			>
+		]}
+	>
+}
+
+impl Rows {
+	fn on_dblclick(self: std::pin::Pin<&Self>, _: lignin::web::Event, _row: usize) {}
+}
+
+fn detached_row(_: std::pin::Pin<&Rows>, _: lignin::web::Event, _row: usize) {}
+
+asteracea::component! {
+	pub Counted()(
+		payload: &'bump std::sync::Arc<()>,
+		count: usize,
+	) -> Sync
+
+	for i: usize in 0..count {[
+		<button
+			on bubble click = with {payload.clone()}: std::sync::Arc<()> fn click(self, _, _) {}
+			!"{}"(i)
+		>
+	]}
+}
+
+#[test]
+fn unclaimed_payloads_are_released() {
+	use std::sync::Arc;
+
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	let component =
+		Box::pin(Counted::new(root.as_ref(), Counted::new_args_builder().build()).unwrap());
+	let payload = Arc::new(());
+	let render = |count: usize| {
+		let bump = bumpalo::Bump::new();
+		component
+			.as_ref()
+			.render(
+				&bump,
+				Counted::render_args_builder()
+					.payload(&payload)
+					.count(count)
+					.build(),
+			)
+			.unwrap();
+	};
+
+	render(3);
+	assert_eq!(Arc::strong_count(&payload), 4);
+
+	render(1);
+	assert_eq!(Arc::strong_count(&payload), 2);
+
+	render(2);
+	assert_eq!(Arc::strong_count(&payload), 3);
+
+	drop(component);
+	assert_eq!(Arc::strong_count(&payload), 1);
+}
+
+#[test]
+fn payload_rows() {
+	use asteracea::services::DomEvents;
+	use rhizome::sync::Inject;
+	use this_is_fine::FineExt;
+
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	// `stop_propagation` requires `DomEvents`.
+	<dyn DomEvents>::inject(root.as_ref(), NoDomEvents)
+		.not_fine()
+		.map_err(|_| ())
+		.unwrap();
+	let component = Box::pin(Rows::new(root.as_ref(), Rows::new_args_builder().build()).unwrap());
+
+	for rows in [&[1, 2, 3][..], &[3], &[4, 5, 6, 7]] {
+		let bump = bumpalo::Bump::new();
+		let vdom = component
+			.as_ref()
+			.render(&bump, Rows::render_args_builder().rows(rows).build())
+			.unwrap();
+
+		let mut html = String::new();
+		lignin_html::render_fragment(&vdom, &mut html, 100).unwrap();
+		assert_eq!(
+			html,
+			format!(
+				"<ul>{}</ul>",
+				"<li><button></button><button></button></li>".repeat(rows.len())
+			)
+		);
+	}
+}
+
+asteracea::component! {
+	pub Shortcuts()() -> Sync?
+
+	<input
+		on bubble keydown[Enter] = fn submit(self, _) {}
+		on bubble prevent_default keydown[Ctrl+S] = fn save(self, _) {}
+		on bubble keyup[Ctrl+Shift+F5] = active Self::on_reload
+		on bubble keydown[Alt+1] = with {1}: usize fn switch_tab(self, _, _) {}
+		on bubble keydown[Space] = async fn toggle(self, _) {}
+	>
+}
+
+impl Shortcuts {
+	fn on_reload(&self, _: lignin::web::Event) {}
+}
+
+asteracea::component! {
+	pub Filtered()() -> Sync
+
+	<input
+		on bubble keydown[Enter] = fn submit(self, _) {}
+	>
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn key_filters_require_dom_events() {
+	use asteracea::{
+		error::{Escalation, RuntimeDependencyMissing},
+		services::DomEvents,
+	};
+	use std::panic::AssertUnwindSafe;
+
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	let caught =
+		Escalation::catch::<_, _, RuntimeDependencyMissing<dyn DomEvents>>(AssertUnwindSafe(
+			|| Filtered::new(root.as_ref(), Filtered::new_args_builder().build()).map(|_| ()),
+		));
+	assert!(caught.is_err());
+}
+
+#[test]
+fn key_filter_matching() {
+	use asteracea::{services::KeyboardState, __::KeyFilter};
+
+	let save = KeyFilter {
+		key: "s",
+		ctrl: true,
+		shift: false,
+		alt: false,
+		meta: false,
+	};
+	let state = |key: &str, ctrl: bool, shift: bool| KeyboardState {
+		key: key.to_string(),
+		ctrl,
+		shift,
+		alt: false,
+		meta: false,
+	};
+
+	assert!(save.matches_state(&state("s", true, false)));
+	assert!(save.matches_state(&state("S", true, false)));
+	assert!(!save.matches_state(&state("s", false, false)));
+	assert!(!save.matches_state(&state("s", true, true)));
+	assert!(!save.matches_state(&state("d", true, false)));
+}
+
+struct NoDomEvents;
+impl asteracea::services::DomEvents for NoDomEvents {
+	fn prevent_default(&self, _: &lignin::web::Event) {
+		unreachable!()
+	}
+
+	fn stop_propagation(&self, _: &lignin::web::Event) {
+		unreachable!()
+	}
+
+	fn keyboard_state(&self, _: &lignin::web::Event) -> Option<asteracea::services::KeyboardState> {
+		unreachable!()
+	}
+}
+
+#[test]
+fn modifiers_with_dom_events() {
+	use asteracea::services::DomEvents;
+	use rhizome::sync::Inject;
+	use this_is_fine::FineExt;
+
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	<dyn DomEvents>::inject(root.as_ref(), NoDomEvents)
+		.not_fine()
+		.map_err(|_| ())
+		.unwrap();
+	let component =
+		Box::pin(Modified::new(root.as_ref(), Modified::new_args_builder().build()).unwrap());
+
+	let bump = bumpalo::Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Modified::render_args_builder().build())
+		.unwrap();
+	match vdom {
+		lignin::Node::HtmlElement { element, .. } => {
+			assert_eq!(element.event_bindings.len(), 3);
+			// `prevent_default` implies `active`.
+			assert!(!element.event_bindings[0].options.passive());
+			assert!(element.event_bindings[1].options.passive());
+			assert!(!element.event_bindings[2].options.passive());
+		}
+		_ => panic!("Expected an HTML element."),
+	}
+}
+
+#[test]
+#[cfg_attr(feature = "error-abort", ignore = "Escalating aborts the process.")]
+fn modifiers_require_dom_events() {
+	use asteracea::{
+		error::{Escalation, RuntimeDependencyMissing},
+		services::DomEvents,
+	};
+	use std::panic::AssertUnwindSafe;
+
+	let root = rhizome::sync::Node::new(std::any::TypeId::of::<()>());
+	let caught =
+		Escalation::catch::<_, _, RuntimeDependencyMissing<dyn DomEvents>>(AssertUnwindSafe(
+			|| Modified::new(root.as_ref(), Modified::new_args_builder().build()).map(|_| ()),
+		));
+	assert!(caught.is_err());
+}
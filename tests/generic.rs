@@ -19,3 +19,29 @@ component! {
 		<*Generic::<u32> .displayed = {1}>
 	]
 }
+
+component! {
+	WhereBound()<T> where T: 'bump + Display, (
+		displayed: T,
+	)
+
+	!(displayed)
+}
+
+component! {
+	WhereBoundNew<> <U> where U: Display, (
+		label: U,
+	)() -> Sync
+
+	let self.label: String = label.to_string();
+	!(self.label)
+}
+
+component! {
+	pub WhereUser()() -> Sync
+
+	[
+		<*WhereBound .displayed = {"bump"}>
+		<*WhereBoundNew *label = {2}>
+	]
+}
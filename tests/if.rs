@@ -1,5 +1,9 @@
 use bumpalo::Bump;
 use lignin::{Node, ThreadBound};
+use std::{
+	any::TypeId,
+	sync::atomic::{AtomicUsize, Ordering},
+};
 
 asteracea::component! {
 	pub Conditional1()(
@@ -36,3 +40,53 @@ asteracea::component! {
 		"]"
 	]
 }
+
+static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+asteracea::component! {
+	Counted()() -> Sync
+
+	new with { CONSTRUCTED.fetch_add(1, Ordering::SeqCst); }
+
+	"Counted"
+}
+
+asteracea::component! {
+	pub DynConditional()(
+		present: bool,
+	) -> Sync
+
+	dyn if {present}
+		<*Counted>
+	else
+		"Absent"
+}
+
+#[test]
+fn dyn_if() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let component = Box::pin(
+		DynConditional::new(root.as_ref(), DynConditional::new_args_builder().build()).unwrap(),
+	);
+	assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 0);
+
+	for (present, constructed) in [(true, 1), (true, 1), (false, 1), (true, 2)] {
+		let bump = Bump::new();
+		let _vdom = component
+			.as_ref()
+			.render(
+				&bump,
+				DynConditional::render_args_builder()
+					.present(present)
+					.build(),
+			)
+			.unwrap();
+		assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), constructed);
+	}
+}
+
+#[test]
+fn dyn_if_is_sync() {
+	fn assert_sync<T: Sync>() {}
+	assert_sync::<DynConditional>();
+}
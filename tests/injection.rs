@@ -0,0 +1,54 @@
+use asteracea::services::{Invalidator, ServiceHandle};
+use rhizome::sync::{Extract, Inject, Node};
+use std::{
+	any::TypeId,
+	sync::atomic::{AtomicUsize, Ordering},
+	task::Context,
+};
+use this_is_fine::FineExt;
+
+static FALLBACK: AtomicUsize = AtomicUsize::new(0);
+static INJECTED: AtomicUsize = AtomicUsize::new(0);
+
+fn fallback() -> ServiceHandle<dyn Invalidator> {
+	let root = Node::new(TypeId::of::<()>());
+	<dyn Invalidator>::inject(root.as_ref(), |_: Option<&mut Context<'_>>| {
+		FALLBACK.fetch_add(1, Ordering::SeqCst);
+	})
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+	<dyn Invalidator as Extract>::extract(root.as_ref())
+		.unwrap()
+		.unwrap()
+}
+
+asteracea::component! {
+	Degrading(
+		pub dyn invalidator?: dyn Invalidator = fallback(),
+	)() -> Sync
+
+	[]
+}
+
+#[test]
+fn optional_dependency_default() {
+	let root = Node::new(TypeId::of::<()>());
+	let degrading = Degrading::new(root.as_ref(), Degrading::new_args_builder().build()).unwrap();
+
+	degrading.invalidator.invalidate();
+	assert_eq!(FALLBACK.load(Ordering::SeqCst), 1);
+	assert_eq!(INJECTED.load(Ordering::SeqCst), 0);
+
+	<dyn Invalidator>::inject(root.as_ref(), |_: Option<&mut Context<'_>>| {
+		INJECTED.fetch_add(1, Ordering::SeqCst);
+	})
+	.not_fine()
+	.map_err(|_| ())
+	.unwrap();
+	let degrading = Degrading::new(root.as_ref(), Degrading::new_args_builder().build()).unwrap();
+
+	degrading.invalidator.invalidate();
+	assert_eq!(FALLBACK.load(Ordering::SeqCst), 1);
+	assert_eq!(INJECTED.load(Ordering::SeqCst), 1);
+}
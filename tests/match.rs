@@ -1,3 +1,11 @@
+use bumpalo::Bump;
+use lignin_html::render_fragment;
+use rhizome::sync::Node;
+use std::{
+	any::TypeId,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
 pub enum Enum<'a> {
 	Text(&'a str),
 	Other,
@@ -13,3 +21,140 @@ asteracea::component! {
 		Enum::Other => <div .class = "placeholder">
 	]
 }
+
+#[derive(Clone, Copy)]
+pub enum Step {
+	First,
+	Second,
+	Done,
+}
+
+static FIRST_CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+static SECOND_CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+asteracea::component! {
+	First()() -> Sync
+
+	new with { FIRST_CONSTRUCTED.fetch_add(1, Ordering::SeqCst); }
+
+	"First"
+}
+
+asteracea::component! {
+	Second()() -> Sync
+
+	new with { SECOND_CONSTRUCTED.fetch_add(1, Ordering::SeqCst); }
+
+	"Second"
+}
+
+asteracea::component! {
+	pub Wizard()(
+		step: Step,
+	) -> Sync
+
+	dyn match {step} [
+		Step::First => <*First>
+		Step::Second => retain <*Second>
+		Step::Done => drop "Done"
+	]
+}
+
+#[test]
+fn dyn_match() {
+	let root = Node::new(TypeId::of::<()>());
+	let component =
+		Box::pin(Wizard::new(root.as_ref(), Wizard::new_args_builder().build()).unwrap());
+
+	for (step, first, second) in [
+		(Step::First, 1, 0),
+		(Step::Second, 1, 1),
+		(Step::First, 2, 1),
+		(Step::Done, 2, 1),
+		(Step::Second, 2, 1),
+		(Step::First, 3, 1),
+	] {
+		let bump = Bump::new();
+		let _vdom = component
+			.as_ref()
+			.render(&bump, Wizard::render_args_builder().step(step).build())
+			.unwrap();
+		assert_eq!(FIRST_CONSTRUCTED.load(Ordering::SeqCst), first);
+		assert_eq!(SECOND_CONSTRUCTED.load(Ordering::SeqCst), second);
+	}
+}
+
+static DROP_ARM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+static RETAIN_ARM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter(&'static AtomicUsize);
+impl Drop for DropCounter {
+	fn drop(&mut self) {
+		self.0.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+asteracea::component! {
+	DropArm()() -> Sync
+
+	let self.dropped: DropCounter = #![allow(dead_code)] DropCounter(&DROP_ARM_DROPPED);
+	let self.renders: AtomicUsize = AtomicUsize::new(0);
+
+	!"Drop {}"(self.renders.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+asteracea::component! {
+	RetainArm()() -> Sync
+
+	let self.dropped: DropCounter = #![allow(dead_code)] DropCounter(&RETAIN_ARM_DROPPED);
+	let self.renders: AtomicUsize = AtomicUsize::new(0);
+
+	!"Retain {}"(self.renders.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+asteracea::component! {
+	pub Policies()(
+		step: Step,
+	) -> Sync
+
+	dyn match {step} [
+		Step::First => drop <*DropArm>
+		Step::Second => retain <*RetainArm>
+		Step::Done => "Done"
+	]
+}
+
+#[test]
+fn dyn_match_policies() {
+	let root = Node::new(TypeId::of::<()>());
+	let component =
+		Box::pin(Policies::new(root.as_ref(), Policies::new_args_builder().build()).unwrap());
+
+	for (step, html, drop_arm_dropped, retain_arm_dropped) in [
+		(Step::First, "Drop 1", 0, 0),
+		(Step::First, "Drop 2", 0, 0),
+		(Step::Second, "Retain 1", 1, 0),
+		(Step::Second, "Retain 2", 1, 0),
+		(Step::First, "Drop 1", 1, 0),
+		(Step::Done, "Done", 2, 0),
+		(Step::Second, "Retain 3", 2, 0),
+	] {
+		let bump = Bump::new();
+		let vdom = component
+			.as_ref()
+			.render(&bump, Policies::render_args_builder().step(step).build())
+			.unwrap();
+
+		let mut rendered = String::new();
+		render_fragment(&vdom, &mut rendered, 1).unwrap();
+		assert_eq!(rendered, html);
+		assert_eq!(DROP_ARM_DROPPED.load(Ordering::SeqCst), drop_arm_dropped);
+		assert_eq!(
+			RETAIN_ARM_DROPPED.load(Ordering::SeqCst),
+			retain_arm_dropped
+		);
+	}
+
+	drop(component);
+	assert_eq!(RETAIN_ARM_DROPPED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,113 @@
+use asteracea::include::DomBinding;
+use bumpalo::Bump;
+use lignin::{web, Node};
+use std::{any::TypeId, pin::Pin};
+
+asteracea::component! {
+	Chart()() -> Sync
+
+	<div
+		<svg ."viewBox" = "0 0 10 10"
+			<circle .r = "5">
+			<foreignObject
+				<span "HTML again">
+			>
+		>
+		<math
+			<mi "x">
+		>
+	>
+}
+
+#[test]
+fn namespaces() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let component = Box::pin(Chart::new(root.as_ref(), Chart::new_args_builder().build()).unwrap());
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Chart::render_args_builder().build())
+		.unwrap();
+
+	let div = match vdom {
+		Node::HtmlElement { element, .. } => element,
+		_ => panic!("Expected `<div>` to be an HTML element."),
+	};
+	let (svg, math) = match div.content {
+		Node::Multi(
+			[Node::SvgElement { element: svg, .. }, Node::MathMlElement { element: math, .. }],
+		) => (svg, math),
+		_ => panic!("Expected `<svg>` and `<math>` to be namespaced."),
+	};
+	assert_eq!(svg.name, "svg");
+	assert_eq!(math.name, "math");
+
+	match svg.content {
+		Node::Multi(
+			[Node::SvgElement {
+				element: circle, ..
+			}, Node::SvgElement {
+				element: foreign_object,
+				..
+			}],
+		) => {
+			assert_eq!(circle.name, "circle");
+			assert!(matches!(foreign_object.content, Node::HtmlElement { .. }));
+		}
+		_ => panic!("Expected SVG children."),
+	}
+	assert!(matches!(math.content, Node::MathMlElement { .. }));
+}
+
+asteracea::component! {
+	Fraction()()
+
+	<math ref self.math
+		<mfrac
+			<mi "a">
+			<mi "b">
+		>
+	>
+}
+
+#[test]
+fn mathml() {
+	let root = rhizome::sync::Node::new(TypeId::of::<()>());
+	let component =
+		Box::pin(Fraction::new(root.as_ref(), Fraction::new_args_builder().build()).unwrap());
+
+	// `lignin` binds MathML elements as plain `web::Element`s.
+	let _: Pin<&DomBinding<web::Element>> = component.as_ref().math_pinned();
+
+	let bump = Bump::new();
+	let vdom = component
+		.as_ref()
+		.render(&bump, Fraction::render_args_builder().build())
+		.unwrap();
+
+	let math = match vdom {
+		Node::MathMlElement {
+			element,
+			dom_binding: Some(_),
+		} => element,
+		_ => panic!("Expected `<math>` to be a bound MathML element."),
+	};
+	assert_eq!(math.name, "math");
+
+	let fraction = match math.content {
+		Node::MathMlElement { element, .. } => element,
+		_ => panic!("Expected `<mfrac>` to be a MathML element."),
+	};
+	assert_eq!(fraction.name, "mfrac");
+
+	match fraction.content {
+		Node::Multi(
+			[Node::MathMlElement { element: a, .. }, Node::MathMlElement { element: b, .. }],
+		) => {
+			assert_eq!(a.name, "mi");
+			assert_eq!(b.name, "mi");
+		}
+		_ => panic!("Expected MathML children."),
+	}
+}